@@ -0,0 +1,4 @@
+//! Macros with the same interface as other crates, implemented on top of
+//! [`maybe_borrow!`](crate::maybe_borrow).
+
+pub mod polonius;
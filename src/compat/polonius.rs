@@ -0,0 +1,249 @@
+//! Drop-in replacements for the macros of
+//! [`polonius-the-crab`](https://docs.rs/polonius-the-crab/latest/polonius_the_crab/), for
+//! migrating call sites incrementally.
+//!
+//! The return type names the borrowed lifetime `'polonius`, as in `polonius-the-crab`.
+//! Unlike the original macros, these also accept several pointers (`|a, b|`), which all share
+//! `'polonius`, and any pointer implementing [`Reborrow`](crate::Reborrow), such as
+//! `Pin<&mut T>`.
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use maybe_borrow::compat::polonius::*;
+//!
+//! fn get_or_insert(mut map: &mut HashMap<u32, String>) -> &String {
+//!     polonius!(|map| -> &'polonius String {
+//!         if let Some(value) = map.get(&22) {
+//!             polonius_return!(value);
+//!         }
+//!     });
+//!
+//!     map.insert(22, String::from("Hi, World!"));
+//!     &map[&22]
+//! }
+//!
+//! assert_eq!(get_or_insert(&mut HashMap::new()), "Hi, World!");
+//! ```
+
+/// Runs the block, returning from the enclosing function with [`polonius_return!`], or evaluating
+/// to the value given to [`exit_polonius!`] (or `()`) with the pointers usable again.
+#[doc(inline)]
+pub use crate::__compat_polonius as polonius;
+
+/// Like [`polonius!`], but runs the block in a loop that's exited with [`polonius_break!`] and
+/// restarted with [`polonius_continue!`].
+///
+/// ```
+/// use std::collections::HashMap;
+/// use maybe_borrow::compat::polonius::*;
+///
+/// /// Returns the value for the first non-empty key that's in the map.
+/// fn first_present<'a>(
+///     mut map: &'a mut HashMap<String, i32>,
+///     keys: &[&str],
+/// ) -> Option<&'a mut i32> {
+///     let mut keys = keys.iter();
+///     polonius_loop!(|map| -> Option<&'polonius mut i32> {
+///         let Some(key) = keys.next() else {
+///             polonius_break!();
+///         };
+///         if key.is_empty() {
+///             polonius_continue!();
+///         }
+///         if let Some(value) = map.get_mut(*key) {
+///             polonius_return!(Some(value));
+///         }
+///     });
+///
+///     map.insert("default".into(), 0);
+///     None
+/// }
+///
+/// let mut map = HashMap::from([("b".to_string(), 1)]);
+/// *first_present(&mut map, &["a", "", "b"]).unwrap() += 1;
+/// assert_eq!(map["b"], 2);
+/// assert!(first_present(&mut map, &["c"]).is_none());
+/// ```
+#[doc(inline)]
+pub use crate::__compat_polonius_loop as polonius_loop;
+
+/// Returns from the function enclosing [`polonius!`] or [`polonius_loop!`] with a value that
+/// may borrow for `'polonius`.
+#[doc(inline)]
+pub use crate::__compat_polonius_return as polonius_return;
+
+/// Like `?`, but returns the error with [`polonius_return!`], converted with [`From`].
+#[doc(inline)]
+pub use crate::__compat_polonius_try as polonius_try;
+
+/// Exits a [`polonius!`] block, making it evaluate to the given value, or `()` if there's none.
+#[doc(inline)]
+pub use crate::__compat_exit_polonius as exit_polonius;
+
+/// Exits a [`polonius_loop!`], making it evaluate to the given value, or `()` if there's none.
+#[doc(inline)]
+pub use crate::__compat_polonius_break as polonius_break;
+
+/// Starts the next iteration of a [`polonius_loop!`].
+#[doc(inline)]
+pub use crate::__compat_polonius_continue as polonius_continue;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius {
+    (|$($var:ident),+ $(,)?| -> $Ret:ty { $($body:tt)* }) => {{
+        // `exit_polonius!` may end the block, leaving the value it's wrapped in unreachable.
+        #[allow(unreachable_code)]
+        let _out = $crate::maybe_borrow!(for<'polonius> |$($var),+| -> $Ret {
+            #[allow(unused_imports)]
+            use $crate::_m::{
+                __compat_exit as __polonius_exit,
+                __compat_outside_loop as __polonius_break,
+                __compat_outside_loop as __polonius_continue,
+            };
+            $($body)*
+        });
+        _out
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius_loop {
+    (|$($var:ident),+ $(,)?| -> $Ret:ty { $($body:tt)* }) => {
+        loop {
+            #[allow(unreachable_code)]
+            let _next = $crate::maybe_borrow!(for<'polonius> |$($var),+| -> $Ret {
+                #[allow(unused_imports)]
+                use $crate::_m::{
+                    __compat_break as __polonius_break,
+                    __compat_continue as __polonius_continue,
+                    __compat_exit_in_loop as __polonius_exit,
+                };
+                let () = { $($body)* };
+                $crate::_m::ControlFlow::Continue(())
+            });
+
+            if let $crate::_m::ControlFlow::Break(_value) = _next {
+                break _value;
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius_return {
+    ($value:expr $(,)?) => {
+        return_borrowed!($value)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius_try {
+    ($value:expr $(,)?) => {
+        match $value {
+            $crate::_m::Ok(_value) => _value,
+            $crate::_m::Err(_error) => {
+                return_borrowed!($crate::_m::Err($crate::_m::From::from(_error)))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_exit_polonius {
+    ($($value:expr)? $(,)?) => {
+        __polonius_exit!($($value)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius_break {
+    ($($value:expr)? $(,)?) => {
+        __polonius_break!($($value)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_polonius_continue {
+    ($(,)?) => {
+        __polonius_continue!()
+    };
+}
+
+// The `polonius!` block is the block passed to `maybe_borrow!`, so these exit it by returning
+// from its closure with the value and the (empty) carried values.
+
+#[cfg(not(maybe_borrow_polonius))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_exit {
+    () => {
+        $crate::_m::__compat_exit!(())
+    };
+    ($value:expr) => {
+        return $crate::_m::ControlFlow::Continue(($value, ()))
+    };
+}
+
+// With `cfg(maybe_borrow_polonius)`, the block runs in place and is exited with `break`.
+#[cfg(maybe_borrow_polonius)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_exit {
+    () => {
+        $crate::_m::__compat_exit!(())
+    };
+    ($value:expr) => {
+        __maybe_borrow_break!($value)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_break {
+    () => {
+        $crate::_m::__compat_break!(())
+    };
+    ($value:expr) => {
+        $crate::_m::__compat_exit!($crate::_m::ControlFlow::Break($value))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_continue {
+    () => {
+        $crate::_m::__compat_exit!($crate::_m::ControlFlow::Continue(()))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_outside_loop {
+    ($($tt:tt)*) => {
+        $crate::_m::compile_error!(
+            "polonius_break! and polonius_continue! are only available within polonius_loop!"
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compat_exit_in_loop {
+    ($($tt:tt)*) => {
+        $crate::_m::compile_error!(
+            "exit_polonius! isn't available within polonius_loop!, use polonius_break! instead"
+        )
+    };
+}
+
+#[doc(hidden)]
+pub use {
+    __compat_break, __compat_continue, __compat_exit, __compat_exit_in_loop, __compat_outside_loop,
+};
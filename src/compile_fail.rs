@@ -39,5 +39,489 @@ should_not_compile!({
                 b
             }
         }
+
+        fn bounded_lifetime_outlives_return() {
+            // should fail because the returned value only lives for the shorter lifetime
+            fn inner<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'a mut i32 {
+                maybe_borrow!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> &'z mut i32 {
+                    if (*a < *b) {
+                        return_borrowed!(b)
+                    }
+                });
+                a
+            }
+        }
+
+        fn unbounded_pointer_lifetime() {
+            // should fail because 'y isn't bounded by the shorter lifetime
+            fn inner<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+                maybe_borrow!(for<'x, 'y, 'z where 'x: 'z> |a, b| -> &'z mut i32 {
+                    if (*a < *b) {
+                        return_borrowed!(b)
+                    }
+                });
+                a
+            }
+        }
+
+        fn bound_from_undeclared_lifetime() {
+            // should fail because 'nonsense isn't one of the pointer lifetimes
+            fn inner<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+                maybe_borrow!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z, 'nonsense: 'z> |a, b| -> &'z mut i32 {
+                    if (*a < *b) {
+                        return_borrowed!(b)
+                    }
+                });
+                a
+            }
+        }
+
+        fn bound_on_pointer_lifetime() {
+            // should fail because the bounded lifetime must come last
+            fn inner<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+                maybe_borrow!(for<'z, 'x, 'y where 'x: 'z, 'y: 'z> |a, b| -> &'z mut i32 {
+                    if (*a < *b) {
+                        return_borrowed!(a)
+                    }
+                });
+                b
+            }
+        }
+    }
+
+    mod trailing_expression {
+        fn returns_pointer() {
+            // should fail because the trailing expression can't reference the reborrowed pointer
+            fn inner(mut a: &mut i32) -> &mut i32 {
+                let out = maybe_borrow!(for<'x> |a| -> () { a });
+                out
+            }
+        }
+
+        fn returns_reborrow() {
+            // should fail because the trailing expression can't borrow from the pointer
+            fn inner(mut a: &mut i32) {
+                let out = maybe_borrow!(for<'x> |a| -> () { &mut *a });
+                *a += 1;
+                *out += 1;
+            }
+        }
+    }
+
+    mod smuggling {
+        fn through_cell() {
+            // should fail because the borrow can't be stored in a captured `Cell`
+            fn inner(mut a: &mut i32) {
+                let cell = std::cell::Cell::new(None);
+                maybe_borrow!(for<'x> |a| -> () {
+                    cell.set(Some(&*a));
+                });
+                *a += 1;
+                let _ = cell.get();
+            }
+        }
+
+        fn through_captured_option() {
+            // should fail because the borrow can't be stored in a captured `&mut Option<_>`
+            fn inner<'a>(mut a: &'a mut i32, slot: &mut Option<&'a i32>) {
+                maybe_borrow!(for<'x> |a| -> () {
+                    *slot = Some(&*a);
+                });
+            }
+        }
+
+        fn through_captured_local() {
+            // should fail because the borrow can't be assigned to a local outside the block
+            fn inner(mut a: &mut i32) {
+                let mut slot: Option<&i32> = None;
+                maybe_borrow!(for<'x> |a| -> () {
+                    slot = Some(&*a);
+                });
+                *a += 1;
+                let _ = slot;
+            }
+        }
+    }
+
+    mod static_lifetime {
+        fn static_return_type() {
+            // should fail because the reborrowed pointer doesn't live for `'static`
+            fn inner(mut a: &mut i32) -> &'static mut i32 {
+                maybe_borrow!(for<'x> |a| -> &'static mut i32 {
+                    return_borrowed!(a);
+                });
+                unreachable!()
+            }
+        }
+
+        fn static_function_return_type() {
+            // should fail because `'x` is the lifetime of `a`, not `'static`
+            fn inner(mut a: &mut i32) -> &'static mut i32 {
+                maybe_borrow!(for<'x> |a| -> &'x mut i32 {
+                    return_borrowed!(a);
+                });
+                unreachable!()
+            }
+        }
+    }
+
+    mod contextual_macros {
+        fn return_borrowed_outside_macro() {
+            // should fail because `return_borrowed!` is only available within the macros
+            fn inner(a: &mut i32) -> &mut i32 {
+                return_borrowed!(a)
+            }
+        }
+
+        fn ready_outside_macro() {
+            // should fail because `ready!` is only available within the macros
+            fn inner(p: std::task::Poll<i32>) -> std::task::Poll<i32> {
+                std::task::Poll::Ready(ready!(p))
+            }
+        }
+    }
+
+    mod continue_with {
+        fn unknown_pointer() {
+            // should fail because `b` isn't one of the pointers passed to the macro
+            fn inner(mut a: &mut Option<Box<i32>>, b: &mut Option<Box<i32>>) {
+                maybe_borrow!(for<'x> |a| -> () {
+                    continue_with!(b, a);
+                });
+            }
+        }
+
+        fn local_replacement() {
+            // should fail because the replacement must be derived from the reborrowed pointer
+            fn inner(mut a: &mut i32) {
+                let mut local = 0;
+                maybe_borrow!(for<'x> |a| -> () {
+                    continue_with!(a, &mut local);
+                });
+                *a += 1;
+            }
+        }
+
+        fn multiple_lifetimes() {
+            // should fail because pointers with distinct lifetimes can't be replaced
+            fn inner<'a, 'b>(mut a: &'a mut Option<Box<i32>>, mut b: &'b mut i32) {
+                maybe_borrow!(for<'x, 'y> |a, b| -> () {
+                    continue_with!(b, b);
+                });
+            }
+        }
+
+        fn reborrowed_as_another_type() {
+            // should fail because the replacement can't be converted back to `Handle`
+            struct Handle<'a>(&'a mut Vec<i32>);
+
+            unsafe impl<'ptr> maybe_borrow::Reborrow<'ptr> for Handle<'ptr> {
+                type BorrowWithLifetime = maybe_borrow::WithLt!['b -> &'b mut Vec<i32>];
+
+                fn reborrow<'b>(this: &'b mut Self) -> &'b mut Vec<i32> {
+                    this.0
+                }
+                unsafe fn extend<'b>(this: Self) -> &'b mut Vec<i32> {
+                    unsafe { &mut *(this.0 as *mut Vec<i32>) }
+                }
+            }
+
+            fn inner(mut values: Handle<'_>) {
+                maybe_borrow!(for<'x> |values| -> () {
+                    continue_with!(values, values);
+                });
+            }
+        }
+
+        fn in_try_maybe_borrow() {
+            // should fail because `continue_with!` isn't available in `try_maybe_borrow!`
+            fn inner(mut a: &mut i32) -> Option<()> {
+                try_maybe_borrow!(for<'x> |a| -> Option<()> {
+                    continue_with!(a, a);
+                });
+                None
+            }
+        }
+    }
+
+    mod carry {
+        fn consumed_on_continue_path() {
+            // should fail because `key` is moved on a path that doesn't return
+            fn inner(mut map: &mut Vec<String>, mut key: String) {
+                maybe_borrow!(for<'x> |map, move key| -> () {
+                    if key.is_empty() {
+                        map.push(key);
+                    }
+                });
+            }
+        }
+
+        fn immutable_binding() {
+            // should fail because carried values are reassigned on the continue path
+            fn inner(mut map: &mut Vec<String>, key: String) {
+                maybe_borrow!(for<'x> |map, move key| -> () {});
+            }
+        }
+
+        fn no_pointers() {
+            // should fail because only owned values are passed to the macro
+            fn inner(mut key: String) {
+                maybe_borrow!(for<'x> |move key| -> () {});
+            }
+        }
+
+        fn borrowed_carry() {
+            // should fail because the carried value can't borrow from the pointer
+            fn inner<'a>(mut map: &'a mut Vec<String>, mut key: &'a str) {
+                maybe_borrow!(for<'x> |map, move key| -> () {
+                    key = &map[0];
+                });
+            }
+        }
+    }
+
+    mod coerced_bindings {
+        fn in_expression_position() {
+            // should fail because bindings can only be declared when used as a statement
+            fn inner(src: &mut Vec<i32>) -> bool {
+                maybe_borrow!(for<'x> |src: &mut [i32]| -> () { src.is_empty() })
+            }
+        }
+    }
+
+    mod maybe_borrow_expr {
+        fn pointer_after_invocation() {
+            // should fail because the pointer is only re-bound on the restored path
+            fn inner(mut a: &mut Vec<i32>) -> usize {
+                let _ = maybe_borrow_expr!(for<'x> |a| -> &'x mut i32 {
+                    if let Some(first) = a.first_mut() {
+                        return_borrowed!(first);
+                    }
+                });
+                a.len()
+            }
+        }
+
+        fn returns_to_function() {
+            // should fail because `a` is still reborrowed by `first`
+            fn inner(mut a: &mut Vec<i32>) -> &mut i32 {
+                let mut b = &mut *a;
+                let first = maybe_borrow_expr!(for<'x> |b| -> &'x mut i32 {
+                    if let Some(first) = b.first_mut() {
+                        return_borrowed!(first);
+                    }
+                });
+                a.push(1);
+                first.borrowed().unwrap()
+            }
+        }
+
+        fn coerced_pointer_after_invocation() {
+            // should fail because coerced pointers are only bound within the invocation
+            fn inner(a: &mut Vec<i32>) -> usize {
+                let _ = maybe_borrow_expr!(for<'x> |b: &mut [i32] = &mut *a| -> &'x mut i32 {
+                    if let Some(first) = b.first_mut() {
+                        return_borrowed!(first);
+                    }
+                } else |()| ());
+                b.len()
+            }
+        }
+    }
+
+    mod inferred_types {
+        fn inferred_return_type() {
+            // should fail because the return type must name the lifetime it borrows for
+            fn inner(mut a: &mut Vec<i32>) -> Option<&mut i32> {
+                maybe_borrow!(for<'x> |a| -> _ {
+                    if let Some(x) = a.first_mut() {
+                        return_borrowed!(Some(x));
+                    }
+                });
+                None
+            }
+        }
+
+        fn inferred_borrowed_part() {
+            // should fail because `_` can't borrow from the pointer
+            fn inner(mut a: &mut Vec<i32>) -> Option<&mut i32> {
+                maybe_borrow!(for<'x> |a| -> Option<_> {
+                    if let Some(x) = a.first_mut() {
+                        return_borrowed!(Some(x));
+                    }
+                });
+                None
+            }
+        }
+    }
+
+    mod compat_polonius {
+        fn break_outside_loop() {
+            // should fail because `polonius_break!` is only available within `polonius_loop!`
+            fn inner(mut a: &mut Vec<i32>) -> &mut i32 {
+                use maybe_borrow::compat::polonius::*;
+                polonius!(|a| -> &'polonius mut i32 {
+                    if a.is_empty() {
+                        polonius_break!();
+                    }
+                });
+                &mut a[0]
+            }
+        }
+
+        fn exit_in_loop() {
+            // should fail because `exit_polonius!` isn't available within `polonius_loop!`
+            fn inner(mut a: &mut Vec<i32>) -> &mut i32 {
+                use maybe_borrow::compat::polonius::*;
+                polonius_loop!(|a| -> &'polonius mut i32 {
+                    exit_polonius!();
+                });
+                &mut a[0]
+            }
+        }
+
+        fn other_lifetime() {
+            // should fail because the borrowed lifetime is named `'polonius`
+            fn inner(mut a: &mut Vec<i32>) -> &mut i32 {
+                use maybe_borrow::compat::polonius::*;
+                polonius!(|a| -> &'x mut i32 {
+                    polonius_return!(&mut a[0]);
+                });
+                &mut a[0]
+            }
+        }
+    }
+
+    mod try_kinds {
+        fn option_into_result_without_error() {
+            // should fail because there's no error to return when `?` is used on `None`
+            fn inner(mut a: &mut Vec<i32>) -> Result<&mut i32, String> {
+                try_maybe_borrow!(for<'x> |a| -> Result<&'x mut i32, String> as Option<_> {
+                    return_borrowed!(Ok(a.first_mut()?));
+                });
+                unreachable!()
+            }
+        }
+
+        fn unconvertible_error() {
+            // should fail because `String` can't be converted from `std::fmt::Error`
+            fn inner(mut a: &mut Vec<i32>) -> Result<&mut i32, String> {
+                try_maybe_borrow!(for<'x> |a| -> Result<&'x mut i32, String> as Result<_, std::fmt::Error> {
+                    Err(std::fmt::Error)?;
+                    return_borrowed!(Ok(&mut a[0]));
+                });
+                unreachable!()
+            }
+        }
+    }
+
+    mod maybe_borrow_match {
+        fn unknown_arm_kind() {
+            // should fail because arms must be tagged with `borrow` or `continue`
+            fn inner(mut a: &mut Option<i32>) -> &mut i32 {
+                maybe_borrow_match!(for<'x> |a| -> &'x mut i32 {
+                    match a {
+                        Some(x) => return x,
+                        None => continue,
+                    }
+                });
+                a.insert(0)
+            }
+        }
+
+        fn untagged_arm() {
+            // should fail because arms must be tagged with `borrow` or `continue`
+            fn inner(mut a: &mut Option<i32>) -> &mut i32 {
+                maybe_borrow_match!(for<'x> |a| -> &'x mut i32 {
+                    match a {
+                        Some(x) => x,
+                        None => continue,
+                    }
+                });
+                a.insert(0)
+            }
+        }
+
+        fn borrow_in_continue_arm() {
+            // should fail because `continue` arms can't hold on to borrowed data
+            fn inner(mut a: &mut Option<i32>) -> Option<&mut i32> {
+                let x = maybe_borrow_match!(for<'x> |a| -> Option<&'x mut i32> {
+                    match a {
+                        Some(x) => continue x,
+                        None => borrow None,
+                    }
+                });
+                Some(x)
+            }
+        }
+    }
+
+    mod produce {
+        fn borrowed_return() {
+            // should fail because the block only has a short reborrow of `values`
+            fn inner(mut values: &mut Vec<i32>) -> &mut i32 {
+                maybe_borrow!(for<'x> |values| -> &'x mut i32 {
+                    if let Some(value) = values.first_mut() {
+                        return_borrowed!(value);
+                    }
+                    if values.len() > 1 {
+                        produce!();
+                    }
+                } produce &mut values[1]);
+                values.push(0);
+                values.last_mut().unwrap()
+            }
+        }
+
+        fn borrowed_value() {
+            // should fail because the value passed to `produce!` borrows from the short reborrow
+            fn inner(mut values: &mut Vec<i32>) -> &mut i32 {
+                maybe_borrow!(for<'x> |values| -> &'x mut i32 {
+                    if let Some(value) = values.first_mut() {
+                        produce!(value);
+                    }
+                } produce |value| value);
+                values.push(0);
+                values.last_mut().unwrap()
+            }
+        }
+
+        fn continue_with() {
+            // should fail because `continue_with!` isn't available with a `produce` arm
+            fn inner(mut values: &mut [i32]) -> &mut i32 {
+                maybe_borrow!(for<'x> |values| -> &'x mut i32 {
+                    if values.len() > 1 {
+                        continue_with!(values, &mut values[1..]);
+                    }
+                    produce!();
+                } produce &mut values[0]);
+                values.last_mut().unwrap()
+            }
+        }
+
+        fn mismatched_return_type() {
+            // should fail because the arm's value isn't the declared return type
+            fn inner(mut values: &mut Vec<i32>) -> &mut i32 {
+                maybe_borrow!(for<'x> |values| -> &'x i32 {
+                    if !values.is_empty() {
+                        produce!();
+                    }
+                } produce &mut values[0]);
+                values.push(0);
+                values.last_mut().unwrap()
+            }
+        }
+
+        fn unbounded_lifetime() {
+            // should fail because every pointer lifetime must outlive the shorter lifetime
+            fn inner<'a>(mut a: &'a mut i32, mut b: &'a mut i32) -> &'a mut i32 {
+                maybe_borrow!(for<'x, 'y, 'z where 'x: 'z> |a, b| -> &'z mut i32 {
+                    if *a < *b {
+                        produce!();
+                    }
+                } produce a);
+                b
+            }
+        }
     }
 });
@@ -0,0 +1,102 @@
+use crate::maybe_borrow;
+
+/// A mutable reference that can descend through a recursive structure without losing access to
+/// the current node when a step fails.
+///
+/// ```
+/// use maybe_borrow::CursorMut;
+///
+/// struct Node {
+///     value: i32,
+///     next: Option<Box<Node>>,
+/// }
+///
+/// /// Appends a value to the end of the list.
+/// fn push_back(list: &mut Node, value: i32) {
+///     let last = CursorMut::new(list).descend(|node| node.next.as_deref_mut());
+///     last.next = Some(Box::new(Node { value, next: None }));
+/// }
+///
+/// let mut list = Node { value: 1, next: None };
+/// push_back(&mut list, 2);
+/// push_back(&mut list, 3);
+/// assert_eq!(list.next.unwrap().next.unwrap().value, 3);
+/// ```
+pub struct CursorMut<'a, T: ?Sized> {
+    node: &'a mut T,
+}
+
+impl<'a, T: ?Sized> CursorMut<'a, T> {
+    /// Creates a cursor pointing at `node`.
+    pub fn new(node: &'a mut T) -> Self {
+        Self { node }
+    }
+
+    /// Returns a shared reference to the current node.
+    pub fn get(&self) -> &T {
+        self.node
+    }
+
+    /// Returns a mutable reference to the current node.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.node
+    }
+
+    /// Converts the cursor into a mutable reference to the current node.
+    pub fn into_mut(self) -> &'a mut T {
+        self.node
+    }
+
+    /// Moves the cursor to the node returned by `step`.
+    ///
+    /// If `step` returns `None`, the cursor is returned unchanged as `Err`.
+    pub fn step(self, step: impl FnOnce(&mut T) -> Option<&mut T>) -> Result<Self, Self> {
+        let mut node = self.node;
+
+        maybe_borrow!(
+            for<'x> |node| -> Result<CursorMut<'x, T>, CursorMut<'x, T>> {
+                if let Some(next) = step(node) {
+                    return_borrowed!(Ok(CursorMut { node: next }));
+                }
+            }
+        );
+
+        Err(Self { node })
+    }
+
+    /// Repeatedly moves the cursor using `step` until it returns `None`, then returns the deepest
+    /// node reached.
+    pub fn descend(self, mut step: impl FnMut(&mut T) -> Option<&mut T>) -> &'a mut T {
+        let mut cursor = self;
+
+        loop {
+            match cursor.step(&mut step) {
+                Ok(next) => cursor = next,
+                Err(last) => return last.into_mut(),
+            }
+        }
+    }
+
+    /// Repeatedly moves the cursor using `step` until it reaches a node matching `predicate`.
+    ///
+    /// Returns `Ok` with the matching node, or `Err` with the deepest node reached if no node
+    /// matched before `step` returned `None`.
+    pub fn find(
+        self,
+        mut predicate: impl FnMut(&T) -> bool,
+        mut step: impl FnMut(&mut T) -> Option<&mut T>,
+    ) -> Result<&'a mut T, &'a mut T> {
+        let mut cursor = self;
+
+        loop {
+            if predicate(cursor.get()) {
+                return Ok(cursor.into_mut());
+            }
+
+            match cursor.step(&mut step) {
+                Ok(next) => cursor = next,
+                Err(last) => return Err(last.into_mut()),
+            }
+        }
+    }
+}
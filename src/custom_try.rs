@@ -3,7 +3,9 @@ use core::{
     task::Poll::{self, Pending, Ready},
 };
 
-type Never = core::convert::Infallible;
+use crate::maybe_borrow_impl::PropagateReturn;
+
+pub type Never = core::convert::Infallible;
 
 pub trait TryKind {
     type WithContinue<C>: CustomTry<Kind = Self, Continue = C>;
@@ -171,3 +173,130 @@ impl<T, E> CustomTry for Poll<Option<Result<T, E>>> {
         }
     }
 }
+
+/// Converts the residual of a `?` in a `try_maybe_borrow!` block of another try kind (declared with
+/// `as`) into the return type.
+pub trait FromOtherResidual<R> {
+    fn from_other_residual(residual: R) -> Self;
+}
+
+impl<T> FromOtherResidual<Option<Never>> for Option<T> {
+    fn from_other_residual(_: Option<Never>) -> Self {
+        None
+    }
+}
+
+impl<T> FromOtherResidual<Option<Never>> for Poll<Option<T>> {
+    fn from_other_residual(_: Option<Never>) -> Self {
+        Ready(None)
+    }
+}
+
+impl<T, E, F: From<E>> FromOtherResidual<Result<Never, E>> for Result<T, F> {
+    fn from_other_residual(Err(e): Result<Never, E>) -> Self {
+        Err(From::from(e))
+    }
+}
+
+impl<T, E, F: From<E>> FromOtherResidual<Result<Never, E>> for Poll<Result<T, F>> {
+    fn from_other_residual(Err(e): Result<Never, E>) -> Self {
+        Ready(Err(From::from(e)))
+    }
+}
+
+impl<T, E, F: From<E>> FromOtherResidual<Result<Never, E>> for Poll<Option<Result<T, F>>> {
+    fn from_other_residual(Err(e): Result<Never, E>) -> Self {
+        Ready(Some(Err(From::from(e))))
+    }
+}
+
+impl<B, C> FromOtherResidual<ControlFlow<B, Never>> for ControlFlow<B, C> {
+    fn from_other_residual(residual: ControlFlow<B, Never>) -> Self {
+        match residual {
+            Break(b) => Break(b),
+        }
+    }
+}
+
+/// Converts the error given with `else` to a `try_maybe_borrow!` block into the return type.
+pub fn from_error<Ret: FromOtherResidual<Result<Never, E>>, E>(error: E) -> Ret {
+    Ret::from_other_residual(Err(error))
+}
+
+/// Converts a value returned with `return_borrowed!` into the output of a
+/// `try_maybe_borrow!` block.
+///
+/// This is implemented on the block's output rather than the returned value so that the returned
+/// value's type can be inferred from the output, allowing bare `Poll::Pending` to be returned.
+pub trait FromBorrowedReturn<R> {
+    fn from_borrowed_return(ret: R) -> Self;
+}
+
+impl<T, C> FromBorrowedReturn<Option<T>> for Option<ControlFlow<T, C>> {
+    fn from_borrowed_return(ret: Option<T>) -> Self {
+        ret.map_continue(Break)
+    }
+}
+
+impl<T, E, C> FromBorrowedReturn<Result<T, E>> for Result<ControlFlow<T, C>, E> {
+    fn from_borrowed_return(ret: Result<T, E>) -> Self {
+        ret.map_continue(Break)
+    }
+}
+
+impl<B, T, C> FromBorrowedReturn<ControlFlow<B, T>> for ControlFlow<B, ControlFlow<T, C>> {
+    fn from_borrowed_return(ret: ControlFlow<B, T>) -> Self {
+        ret.map_continue(Break)
+    }
+}
+
+impl<T, E, C> FromBorrowedReturn<Poll<Result<T, E>>> for Poll<Result<ControlFlow<T, C>, E>> {
+    fn from_borrowed_return(ret: Poll<Result<T, E>>) -> Self {
+        ret.map_continue(Break)
+    }
+}
+
+impl<T, E, C> FromBorrowedReturn<Poll<Option<Result<T, E>>>>
+    for Poll<Option<Result<ControlFlow<T, C>, E>>>
+{
+    fn from_borrowed_return(ret: Poll<Option<Result<T, E>>>) -> Self {
+        ret.map_continue(Break)
+    }
+}
+
+/// Nested inside a `try_maybe_borrow!` block, borrowed values are returned from the block in the
+/// same way as with `return_borrowed!`.
+macro_rules! propagate_through_try {
+    ($(impl<$($param:ident),*> for $Out:ty, $Ret:ty;)*) => {$(
+        impl<$($param),*> PropagateReturn<$Ret> for $Out {
+            fn propagate(ret: $Ret) -> Self {
+                Self::from_borrowed_return(ret)
+            }
+        }
+    )*};
+}
+
+propagate_through_try! {
+    impl<T, C> for Option<ControlFlow<T, C>>, Option<T>;
+    impl<T, E, C> for Result<ControlFlow<T, C>, E>, Result<T, E>;
+    impl<B, T, C> for ControlFlow<B, ControlFlow<T, C>>, ControlFlow<B, T>;
+    impl<T, E, C> for Poll<Result<ControlFlow<T, C>, E>>, Poll<Result<T, E>>;
+    impl<T, E, C> for Poll<Option<Result<ControlFlow<T, C>, E>>>, Poll<Option<Result<T, E>>>;
+}
+
+/// Nested inside a `try_maybe_borrow!` block declared with `as`, the block's output breaks with the
+/// borrowed value itself.
+macro_rules! propagate_through_try_as {
+    ($(impl<$($param:ident),*> for $Out:ty;)*) => {$(
+        impl<R, $($param),*> PropagateReturn<R> for $Out {
+            fn propagate(ret: R) -> Self {
+                Self::from_continue(Break(ret))
+            }
+        }
+    )*};
+}
+
+propagate_through_try_as! {
+    impl<C> for Option<ControlFlow<R, C>>;
+    impl<E, C> for Result<ControlFlow<R, C>, E>;
+}
@@ -1,30 +1,53 @@
 //! [`maybe_borrow!`]: maybe_borrow
 //! [`try_maybe_borrow!`]: try_maybe_borrow
+//! [`maybe_borrow_expr!`]: maybe_borrow_expr
+//! [`maybe_borrow_match!`]: maybe_borrow_match
+//! [`impl_reborrow!`]: impl_reborrow
+//! [`CursorMut`]: CursorMut
+//! [`RawMut`]: RawMut
+//! [`compat::polonius`]: compat::polonius
 #![doc = include_str!("../README.md")]
 #![no_std]
 
 extern crate should_it_compile;
 
+pub mod compat;
+mod cursor;
 mod custom_try;
 mod macros;
 mod ptr_impls;
+mod raw;
 mod utils;
 mod with_lt;
 
+// The compile-fail cases check the expansion that's used without `cfg(maybe_borrow_polonius)`.
+#[cfg(not(maybe_borrow_polonius))]
 should_it_compile::compile_test_mod!(compile_fail);
 
 pub mod prelude {
-    pub use crate::macros::{maybe_borrow, try_maybe_borrow};
+    pub use crate::macros::{
+        maybe_borrow, maybe_borrow_expr, maybe_borrow_match, try_maybe_borrow,
+    };
 }
 
 #[doc(hidden)]
 pub mod _m {
-    use crate::custom_try::TryKind;
+    use crate::custom_try::{Never, ResidualOf, TryKind};
     pub use crate::{
-        custom_try::{ContinueOf, CustomTry, WithContinue},
+        compat::polonius::{
+            __compat_break, __compat_continue, __compat_exit, __compat_exit_in_loop,
+            __compat_outside_loop,
+        },
+        custom_try::{
+            from_error, ContinueOf, CustomTry, FromBorrowedReturn, FromOtherResidual, WithContinue,
+        },
         macros::*,
-        maybe_borrow_impl::maybe_borrow,
+        maybe_borrow_impl::{
+            maybe_borrow, maybe_borrow_bounded, maybe_borrow_produce, Bounded, EarlyExit,
+            PointerLifetime, ProduceExit, PropagateReturn,
+        },
         with_lt::*,
+        WithLt,
     };
     pub use core::{
         self,
@@ -35,6 +58,42 @@ pub mod _m {
         task::Poll,
     };
 
+    /// Wraps the result of a nested [`maybe_borrow`] call so it can be returned from the
+    /// enclosing block.
+    #[inline(always)]
+    pub fn return_nested<R, P, C>(ctrl: ControlFlow<R, C>) -> ControlFlow<EarlyExit<R, P, C>, C> {
+        match ctrl {
+            Break(r) => Break(EarlyExit::Return(r)),
+            Continue(c) => Continue(c),
+        }
+    }
+
+    /// Wraps the result of a nested [`maybe_borrow_bounded`] call so it can be returned from the
+    /// enclosing block, dropping the reborrows it hands back.
+    #[inline(always)]
+    pub fn return_bounded_nested<R, P, C, Ptr, Outer>(
+        ctrl: ControlFlow<R, (C, Ptr, Outer)>,
+    ) -> ControlFlow<EarlyExit<R, P, C>, C> {
+        match ctrl {
+            Break(r) => Break(EarlyExit::Return(r)),
+            Continue((c, _, _)) => Continue(c),
+        }
+    }
+
+    /// Converts the pointer a block replaced the original with back to the original's type.
+    /// `continue_with!` passes this to [`maybe_borrow`] as a function pointer, which requires the
+    /// two types to be the same.
+    #[inline(always)]
+    pub fn restore_replaced<P>(ptr: P) -> P {
+        ptr
+    }
+
+    /// Whether [`maybe_borrow`] drops the original pointer when the block replaces it or returns
+    /// borrowed data, rather than forgetting it.
+    /// With `cfg(maybe_borrow_polonius)`, the block uses the original itself, which is dropped as
+    /// usual.
+    pub const DROPS_REPLACED_POINTERS: bool = cfg!(maybe_borrow_polonius);
+
     /// Container that's not [`Copy`] so it automatically gets moved into a closure
     /// rather than referenced.
     pub struct ForceMove<T>(pub T);
@@ -56,21 +115,56 @@ pub mod _m {
         }
     }
 
-    pub fn try_maybe_borrow_helper<Tk, Ret, T, Exit>(
+    pub fn try_maybe_borrow_helper<Tk, Ret, T, Exit, P>(
         body: impl FnOnce(
             WrapTryMaybeBorrowExit<Tk::WithContinue<ControlFlow<T, Exit>>>,
         ) -> Tk::WithContinue<ControlFlow<T, Exit>>,
-    ) -> ControlFlow<Ret, Exit>
+    ) -> ControlFlow<EarlyExit<Ret, P, Exit>, Exit>
     where
         Tk: TryKind,
         Ret: CustomTry<Kind = Tk, Continue = T>,
     {
         let body_out = body(WrapTryMaybeBorrowExit(PhantomData));
         match body_out.into_ctrl() {
-            Break(r) => Break(Ret::from_residual(r)),
-            Continue(Ok(Break(b))) => Break(Ret::from_continue(b)),
+            Break(r) => Break(EarlyExit::Return(Ret::from_residual(r))),
+            Continue(Ok(Break(b))) => Break(EarlyExit::Return(Ret::from_continue(b))),
             Continue(Ok(Continue(exit))) => Continue(exit),
-            Continue(Err(e)) => Break(Ret::from_empty(e)),
+            Continue(Err(e)) => Break(EarlyExit::Return(Ret::from_empty(e))),
+        }
+    }
+
+    /// Like [`try_maybe_borrow_helper`], but `?` in the body uses the try kind of `Body`, and its
+    /// residual is converted to `Ret` with `convert`.
+    pub fn try_maybe_borrow_as_helper<Body, Ret, Exit, P>(
+        body: impl FnOnce(WrapTryMaybeBorrowExit<Body>) -> Body,
+        convert: impl FnOnce(ResidualOf<Body>) -> Ret,
+    ) -> ControlFlow<EarlyExit<Ret, P, Exit>, Exit>
+    where
+        Body: CustomTry<Continue = ControlFlow<Ret, Exit>, Kind: TryKind<Empty = Never>>,
+    {
+        match body(WrapTryMaybeBorrowExit(PhantomData)).into_ctrl() {
+            Break(r) => Break(EarlyExit::Return(convert(r))),
+            Continue(Ok(Break(ret))) => Break(EarlyExit::Return(ret)),
+            Continue(Ok(Continue(exit))) => Continue(exit),
+            Continue(Err(e)) => match e {},
+        }
+    }
+
+    /// Like [`try_maybe_borrow_as_helper`], but with `cfg(maybe_borrow_polonius)` the pointers
+    /// and carried values are moved into `body` and handed back on the continue path.
+    #[cfg(maybe_borrow_polonius)]
+    pub fn try_maybe_borrow_as_native<Body, Ret, Out, State>(
+        state: State,
+        body: impl FnOnce(State) -> Body,
+        convert: impl FnOnce(ResidualOf<Body>) -> Ret,
+    ) -> ControlFlow<Ret, (Out, State)>
+    where
+        Body: CustomTry<Continue = ControlFlow<Ret, (Out, State)>, Kind: TryKind<Empty = Never>>,
+    {
+        match body(state).into_ctrl() {
+            Break(r) => Break(convert(r)),
+            Continue(Ok(c)) => c,
+            Continue(Err(e)) => match e {},
         }
     }
 }
@@ -78,3 +172,11 @@ pub mod _m {
 mod traits;
 
 mod maybe_borrow_impl;
+
+pub use crate::{
+    cursor::CursorMut,
+    maybe_borrow_impl::{BorrowedAs, Outcome},
+    raw::{RawMut, RawRef},
+    traits::{BorrowWithLifetime, Reborrow, ReborrowFields},
+    with_lt::{Apply, WithLt, WithLt2, WithLt2Hrtb, WithLtHrtb},
+};
@@ -7,7 +7,11 @@
 #[doc = include_str!("./maybe_borrow.md")]
 #[macro_export]
 macro_rules! maybe_borrow {
-    ( $(for<$lt:lifetime>)? |$($ptr:ident),*| -> $Ret:ty $block:block ) => {
+    (
+        $(for<$($lt:lifetime),+ $(where $($long:lifetime: $short:lifetime),+)?>)?
+        |$($ptr:ident $(: $Ptr:ty)? $(= $($init:tt)+)?),* $(, move $carry:ident)*|
+        -> $Ret:ty $block:block $(produce $(|$value:pat_param|)? $produce:expr)?
+    ) => {
         todo!()
     };
 }
@@ -16,87 +20,1245 @@ macro_rules! maybe_borrow {
 #[doc = include_str!("./try_maybe_borrow.md")]
 #[macro_export]
 macro_rules! try_maybe_borrow {
-    ( $(for<$lt:lifetime>)? |$($ptr:ident),*| -> $Ret:ty $block:block ) => {
+    (
+        $(for<$lt:lifetime>)?
+        |$($ptr:ident $(: $Ptr:ty)? $(= $($init:tt)+)?),* $(, move $carry:ident)*|
+        -> $Ret:ty $(as $Body:ty)? $block:block $(else $error:expr)?
+    ) => {
+        todo!()
+    };
+}
+
+#[cfg(doc)]
+#[doc = include_str!("./maybe_borrow_expr.md")]
+#[macro_export]
+macro_rules! maybe_borrow_expr {
+    (
+        $(for<$($lt:lifetime),+ $(where $($long:lifetime: $short:lifetime),+)?>)?
+        |$($ptr:ident $(: $Ptr:ty)? $(= $($init:tt)+)?),* $(, move $carry:ident)*|
+        -> $Borrowed:ty $block:block $(else |$value:pat_param| $restored:expr)?
+    ) => {
         todo!()
     };
 }
 
-#[cfg(doc)]
-#[macro_export]
-/// Return from the containing function with potentially borrowed data from within a
-/// [`maybe_borrow`] or [`try_maybe_borrow`] invocation.
-///
-/// This macro is only available within the aforementioned macros.
-macro_rules! return_borrowed {
-    ($return_value:expr) => {};
-}
-
-#[cfg(doc)]
-#[macro_export]
-/// Behaves like [`core::task::ready!`], but uses [`return_borrowed!`] rather than `return` so it can be used within [`maybe_borrow!`] or [`try_maybe_borrow!`].
-///
-/// This macro is only available within the aforementioned macros.
-macro_rules! ready {
-    ($return_value:expr) => {};
-}
-
-#[cfg(doc)]
-pub use return_borrowed;
-
-// MARK: Public
+#[cfg(doc)]
+#[doc = include_str!("./maybe_borrow_match.md")]
+#[macro_export]
+macro_rules! maybe_borrow_match {
+    (
+        $(for<$($lt:lifetime),+ $(where $($long:lifetime: $short:lifetime),+)?>)?
+        |$($ptr:ident $(: $Ptr:ty)? $(= $($init:tt)+)?),* $(, move $carry:ident)*|
+        -> $Ret:ty {
+            match $($scrutinee:tt)+ {
+                $($pat:pat $(if $guard:expr)? => $(borrow)? $(continue)? $body:expr),*
+            }
+        }
+    ) => {
+        todo!()
+    };
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Return from the containing function with potentially borrowed data from within a
+/// [`maybe_borrow`] or [`try_maybe_borrow`] invocation.
+///
+/// Within [`maybe_borrow_expr`], this instead makes the invocation evaluate to
+/// [`Outcome::Borrowed`](crate::Outcome::Borrowed).
+///
+/// This macro is only available within the aforementioned macros.
+macro_rules! return_borrowed {
+    ($return_value:expr) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Behaves like [`core::task::ready!`], but uses [`return_borrowed!`] rather than `return` so it can be used within [`maybe_borrow!`] or [`try_maybe_borrow!`].
+///
+/// This macro is only available within the aforementioned macros.
+macro_rules! ready {
+    ($return_value:expr) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Unwraps a `Poll<Option<T>>`, returning `Poll::Pending` or `Poll::Ready(None)` with
+/// [`return_borrowed!`] if the value isn't `Poll::Ready(Some(_))`.
+///
+/// This macro is only available within [`maybe_borrow!`] and related macros.
+macro_rules! ready_some {
+    ($poll:expr) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Unwraps a `Poll<Option<Result<T, E>>>`, returning `Poll::Pending`, `Poll::Ready(None)`, or
+/// `Poll::Ready(Some(Err(_)))` with [`return_borrowed!`] if the value isn't
+/// `Poll::Ready(Some(Ok(_)))`.
+///
+/// Like `?`, the error is converted with [`From`].
+///
+/// This macro is only available within [`maybe_borrow!`] and related macros.
+macro_rules! try_ready {
+    ($poll:expr) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Shorthand for `return_borrowed!(Poll::Ready($value))`.
+///
+/// This macro is only available within [`maybe_borrow!`] and related macros.
+macro_rules! return_ready {
+    ($value:expr) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Exit a [`maybe_borrow!`] block early, replacing `$ptr` with a new pointer derived from it.
+///
+/// The replacement has the same lifetime as the original pointer, so this can be used to descend
+/// through recursive structures like linked lists and trees.
+/// The `maybe_borrow!` invocation evaluates to `$value`, or `()` if it's omitted.
+///
+/// This macro is only available within `maybe_borrow!` invocations that use at most one
+/// lifetime.
+macro_rules! continue_with {
+    ($ptr:ident, $new_ptr:expr $(, $value:expr)?) => {};
+}
+
+#[cfg(doc)]
+#[macro_export]
+/// Exit a [`maybe_borrow!`] block that has a `produce` arm, evaluating the arm with `$value`, or
+/// `()` if it's omitted.
+///
+/// This macro is only available within `maybe_borrow!` invocations with a `produce` arm.
+macro_rules! produce {
+    ($($value:expr)?) => {};
+}
+
+#[cfg(doc)]
+pub use {continue_with, produce, ready, ready_some, return_borrowed, return_ready, try_ready};
+
+// MARK: Public
+
+/// Runs the given block, either returning a value borrowing data from `$ptr`'s target, or
+/// continuing by evaluating to a non-borrowing value.
+///
+#[cfg(not(doc))]
+#[doc = include_str!("./maybe_borrow.md")]
+#[macro_export]
+macro_rules! maybe_borrow {
+    (
+        for<$($lt:lifetime),+ where $($long:lifetime: $short:lifetime),+ $(,)?>
+        | $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            (maybe_borrow return) [] [$($lt)*] [$($long: $short),+] $($rest)*
+        }
+    };
+    ($(for<$($lt:lifetime),* $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (maybe_borrow [$($($lt)*)?] return) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use maybe_borrow;
+
+#[cfg(not(doc))]
+#[doc = include_str!("./try_maybe_borrow.md")]
+#[macro_export]
+macro_rules! try_maybe_borrow {
+    ($(for<$lt:lifetime $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (try_maybe_borrow [$($lt)?] return) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use try_maybe_borrow;
+
+#[cfg(not(doc))]
+#[doc = include_str!("./maybe_borrow_expr.md")]
+#[macro_export]
+macro_rules! maybe_borrow_expr {
+    (
+        for<$($lt:lifetime),+ where $($long:lifetime: $short:lifetime),+ $(,)?>
+        | $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            (maybe_borrow_expr return) [] [$($lt)*] [$($long: $short),+] $($rest)*
+        }
+    };
+    ($(for<$($lt:lifetime),* $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (maybe_borrow_expr [$($($lt)*)?] return) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use maybe_borrow_expr;
+
+#[cfg(not(doc))]
+#[doc = include_str!("./maybe_borrow_match.md")]
+#[macro_export]
+macro_rules! maybe_borrow_match {
+    (
+        for<$($lt:lifetime),+ where $($long:lifetime: $short:lifetime),+ $(,)?>
+        | $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            (maybe_borrow_match return) [] [$($lt)*] [$($long: $short),+] $($rest)*
+        }
+    };
+    ($(for<$($lt:lifetime),* $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (maybe_borrow_match [$($($lt)*)?] return) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use maybe_borrow_match;
+
+/// Implements [`Reborrow`](crate::Reborrow) for a struct whose fields are all pointers that
+/// implement `Reborrow` for the struct's lifetime parameter.
+///
+/// The struct's first generic parameter must be the lifetime of its pointers, and every field must
+/// be listed.
+/// This composes with the projection types generated by `pin-project-lite` (via
+/// `#[project = ...]`), allowing a whole projection to be passed to [`maybe_borrow!`] as a single
+/// pointer.
+///
+/// ```
+/// use std::pin::Pin;
+/// use maybe_borrow::prelude::*;
+///
+/// struct Fields<'a, T> {
+///     buffer: &'a mut Vec<T>,
+///     fallback: Pin<&'a mut T>,
+/// }
+///
+/// maybe_borrow::impl_reborrow! {
+///     impl<T> Reborrow for Fields<'_, T> { buffer, fallback }
+/// }
+///
+/// /// Returns the first buffered item, or the fallback if the buffer is empty.
+/// fn first_or_fallback<T: Unpin>(mut fields: Fields<T>) -> &mut T {
+///     maybe_borrow!(for<'x> |fields| -> &'x mut T {
+///         if let Some(first) = fields.buffer.first_mut() {
+///             return_borrowed!(first);
+///         }
+///     });
+///
+///     fields.fallback.get_mut()
+/// }
+///
+/// let (mut buffer, mut fallback) = (vec![], 1);
+/// *first_or_fallback(Fields { buffer: &mut buffer, fallback: Pin::new(&mut fallback) }) += 1;
+/// assert_eq!(fallback, 2);
+/// ```
+///
+/// For other pointer bundles, such as tuple structs, implement
+/// [`ReborrowFields`](crate::ReborrowFields) and use `via ReborrowFields` in place of the field
+/// list.
+#[macro_export]
+macro_rules! impl_reborrow {
+    (
+        impl $(<$($param:tt),* $(,)?>)? Reborrow for $Name:ident<'_ $(, $arg:ty)* $(,)?> {
+            $($field:ident),* $(,)?
+        }
+        $(where $($bounds:tt)*)?
+    ) => {
+        unsafe impl<'__ptr, $($($param),*)?> $crate::Reborrow<'__ptr> for $Name<'__ptr, $($arg),*>
+        $(where $($bounds)*)?
+        {
+            type BorrowWithLifetime = $crate::WithLt!['__b -> $Name<'__b, $($arg),*>];
+
+            fn reborrow<'__b>(this: &'__b mut Self) -> $Name<'__b, $($arg),*> {
+                $Name {
+                    $($field: $crate::Reborrow::reborrow(&mut this.$field),)*
+                }
+            }
+
+            unsafe fn extend<'__b>(this: Self) -> $Name<'__b, $($arg),*> {
+                let $Name { $($field),* } = this;
+                unsafe {
+                    $Name {
+                        $($field: $crate::Reborrow::extend($field),)*
+                    }
+                }
+            }
+        }
+    };
+    (
+        impl $(<$($param:tt),* $(,)?>)? Reborrow for $Name:ident<'_ $(, $arg:ty)* $(,)?>
+        via ReborrowFields
+        $(where $($bounds:tt)*)?
+    ) => {
+        unsafe impl<'__ptr, $($($param),*)?> $crate::Reborrow<'__ptr> for $Name<'__ptr, $($arg),*>
+        $(where $($bounds)*)?
+        {
+            type BorrowWithLifetime = $crate::WithLt!['__b -> $Name<'__b, $($arg),*>];
+
+            fn reborrow<'__b>(this: &'__b mut Self) -> $Name<'__b, $($arg),*> {
+                $crate::ReborrowFields::from_fields($crate::ReborrowFields::reborrow_fields(this))
+            }
+
+            unsafe fn extend<'__b>(this: Self) -> $Name<'__b, $($arg),*> {
+                // SAFETY: The fields are the bundle's pointers, so extending them has the same
+                // requirements as extending the bundle.
+                let fields = unsafe {
+                    $crate::Reborrow::extend($crate::ReborrowFields::into_fields(this))
+                };
+                $crate::ReborrowFields::from_fields(fields)
+            }
+        }
+    };
+}
+
+pub use impl_reborrow;
+
+/// Generates tests checking that a [`Reborrow`](crate::Reborrow) implementation upholds its safety
+/// contract, for running under [Miri](https://github.com/rust-lang/miri).
+///
+/// The tests are placed in a module named `$name`.
+/// `$with` is a closure that creates a pointer of type `$Ty` and passes it to the `check` function
+/// it's given, which returns how many times the original pointer should have been dropped (`0` or
+/// `1`), so that types with drop glue can check their own drop counts.
+/// Each test calls `$with` once, with a `check` that:
+///
+/// - reborrows the pointer repeatedly, including reborrows of reborrows,
+/// - extends a reborrow and drops it before using the pointer again,
+/// - runs `maybe_borrow!` blocks that continue, replace the pointer with `continue_with!`, return
+///   borrowed data, and panic.
+///
+/// With `cfg(maybe_borrow_polonius)`, blocks that replace the pointer or return borrowed data
+/// drop the original pointer instead of forgetting it.
+///
+/// If the pointer implements [`Deref`](core::ops::Deref) and is declared with `: Deref`, the tests
+/// also check that reborrowing, extending, continuing and `continue_with!` keep the same target,
+/// and run the pointer through `maybe_borrow!` inside a [`Pin`](core::pin::Pin), which requires
+/// the target to be [`Unpin`]. Declaring it with `: Deref + ?Unpin` skips the `Pin` test, so a
+/// pinned pointer to a `!Unpin` target (`for<'a> Pin<&'a mut T>: Deref + ?Unpin`) can check that
+/// its target is never moved.
+///
+/// ```
+/// use maybe_borrow::prelude::*;
+///
+/// struct Split<'a> {
+///     head: &'a mut Vec<i32>,
+///     tail: &'a [i32],
+/// }
+///
+/// maybe_borrow::impl_reborrow! {
+///     impl Reborrow for Split<'_> { head, tail }
+/// }
+///
+/// maybe_borrow::reborrow_conformance_tests!(split, for<'a> Split<'a>, |check| {
+///     let mut head = vec![1, 2];
+///     check(Split { head: &mut head, tail: &[3] });
+///     assert_eq!(head, [1, 2]);
+/// });
+///
+/// maybe_borrow::reborrow_conformance_tests!(slice, for<'a> &'a mut [i32]: Deref, |check| {
+///     check(&mut [1, 2, 3]);
+/// });
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! reborrow_conformance_tests {
+    ($name:ident, for<$lt:lifetime> $Ty:ty: Deref + ?Unpin, $with:expr $(,)?) => {
+        $crate::reborrow_conformance_tests! {
+            @tests $name, for<$lt> $Ty, $with,
+            $crate::reborrow_conformance_tests! { @address for<$lt> $Ty }
+        }
+    };
+    ($name:ident, for<$lt:lifetime> $Ty:ty: Deref, $with:expr $(,)?) => {
+        $crate::reborrow_conformance_tests! {
+            @tests $name, for<$lt> $Ty, $with,
+            $crate::reborrow_conformance_tests! { @address for<$lt> $Ty }
+
+            #[test]
+            fn pinned() {
+                fn check<$lt>(ptr: $Ty) -> usize {
+                    let mut ptr = ::core::pin::Pin::new(ptr);
+                    let target = address(&*ptr);
+                    for _ in 0..2 {
+                        maybe_borrow!(for<'x> |ptr| -> usize {
+                            assert_eq!(address(&*ptr), target);
+                            drop(Reborrow::reborrow(&mut ptr));
+                        });
+                    }
+                    assert_eq!(address(&*ptr), target);
+                    1
+                }
+                with_pointer(check);
+            }
+        }
+    };
+    ($name:ident, for<$lt:lifetime> $Ty:ty, $with:expr $(,)?) => {
+        $crate::reborrow_conformance_tests! { @tests $name, for<$lt> $Ty, $with, }
+    };
+    (@address for<$lt:lifetime> $Ty:ty) => {
+        /// Returns the address of `target`, without any metadata.
+        fn address<T: ?Sized>(target: &T) -> *const () {
+            target as *const T as *const ()
+        }
+
+        #[test]
+        fn reborrow_keeps_target() {
+            fn check<$lt>(mut ptr: $Ty) -> usize {
+                let target = address(&*ptr);
+                let reborrowed = Reborrow::reborrow(&mut ptr);
+                assert_eq!(address(&*reborrowed), target);
+
+                // SAFETY: The extended pointer is dropped before `ptr` is used again.
+                let extended = unsafe { Reborrow::extend(reborrowed) };
+                assert_eq!(address(&*extended), target);
+                drop(extended);
+
+                assert_eq!(address(&*ptr), target);
+                1
+            }
+            with_pointer(check);
+        }
+
+        #[test]
+        fn maybe_borrow_keeps_target() {
+            fn check<$lt>(mut ptr: $Ty) -> usize {
+                let target = address(&*ptr);
+                for replace in [false, true] {
+                    let replaced = maybe_borrow!(for<'x> |ptr| -> usize {
+                        assert_eq!(address(&*Reborrow::reborrow(&mut ptr)), target);
+                        if replace {
+                            continue_with!(ptr, ptr, true);
+                        }
+                        false
+                    });
+                    assert_eq!(replaced, replace);
+                    assert_eq!(address(&*ptr), target);
+                }
+                drop(ptr);
+                $crate::_m::DROPS_REPLACED_POINTERS as usize
+            }
+            with_pointer(check);
+        }
+    };
+    (@tests $name:ident, for<$lt:lifetime> $Ty:ty, $with:expr, $($extra:item)*) => {
+        mod $name {
+            #![allow(unused_imports, clippy::drop_non_drop)]
+            use super::*;
+            use $crate::{prelude::*, Reborrow};
+
+            fn with_pointer(check: for<$lt> fn($Ty) -> usize) {
+                fn call(
+                    with: impl FnOnce(for<$lt> fn($Ty) -> usize),
+                    check: for<$lt> fn($Ty) -> usize,
+                ) {
+                    with(check)
+                }
+                call($with, check)
+            }
+
+            fn fail() {
+                panic!("expected panic from reborrow conformance test");
+            }
+
+            #[test]
+            fn reborrow_round_trip() {
+                fn check<$lt>(mut ptr: $Ty) -> usize {
+                    for _ in 0..3 {
+                        let mut reborrowed = Reborrow::reborrow(&mut ptr);
+                        drop(Reborrow::reborrow(&mut reborrowed));
+                        drop(reborrowed);
+                    }
+                    drop(ptr);
+                    1
+                }
+                with_pointer(check);
+            }
+
+            #[test]
+            fn extend_round_trip() {
+                fn check<$lt>(mut ptr: $Ty) -> usize {
+                    for _ in 0..3 {
+                        let reborrowed = Reborrow::reborrow(&mut ptr);
+                        // SAFETY: The extended pointer is dropped before `ptr` is used again.
+                        let extended = unsafe { Reborrow::extend(reborrowed) };
+                        drop(extended);
+                    }
+                    drop(Reborrow::reborrow(&mut ptr));
+                    drop(ptr);
+                    1
+                }
+                with_pointer(check);
+            }
+
+            #[test]
+            fn continue_path() {
+                fn check<$lt>(mut ptr: $Ty) -> usize {
+                    for _ in 0..3 {
+                        maybe_borrow!(for<'x> |ptr| -> usize {
+                            drop(Reborrow::reborrow(&mut ptr));
+                        });
+                    }
+                    drop(Reborrow::reborrow(&mut ptr));
+                    drop(ptr);
+                    1
+                }
+                with_pointer(check);
+            }
+
+            #[test]
+            fn replace_path() {
+                fn check<$lt>(mut ptr: $Ty) -> usize {
+                    // The original is forgotten, and the replacement is dropped in its place.
+                    for replace in [false, true] {
+                        let replaced = maybe_borrow!(for<'x> |ptr| -> usize {
+                            if replace {
+                                continue_with!(ptr, ptr, true);
+                            }
+                            false
+                        });
+                        assert_eq!(replaced, replace);
+                    }
+                    drop(Reborrow::reborrow(&mut ptr));
+                    drop(ptr);
+                    $crate::_m::DROPS_REPLACED_POINTERS as usize
+                }
+                with_pointer(check);
+            }
+
+            #[test]
+            fn return_path() {
+                type Returned<'b, $lt> = $crate::BorrowedAs<
+                    'b,
+                    <$Ty as Reborrow<$lt>>::BorrowWithLifetime,
+                >;
+
+                fn returned<$lt>(mut ptr: $Ty) -> Returned<$lt, $lt> {
+                    for ret in [false, true] {
+                        maybe_borrow!(for<'x> |ptr| -> Returned<'x, $lt> {
+                            drop(Reborrow::reborrow(&mut ptr));
+                            if ret {
+                                return_borrowed!(ptr);
+                            }
+                        });
+                    }
+                    unreachable!("the block should have returned")
+                }
+
+                fn check<$lt>(ptr: $Ty) -> usize {
+                    // The original is forgotten, and the reborrow it returned is used in its place.
+                    let mut returned = returned(ptr);
+                    drop(Reborrow::reborrow(&mut returned));
+                    drop(returned);
+                    $crate::_m::DROPS_REPLACED_POINTERS as usize
+                }
+                with_pointer(check);
+            }
+
+            #[test]
+            fn unwind_path() {
+                fn check<$lt>(ptr: $Ty) -> usize {
+                    let result = ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                        let mut ptr = ptr;
+                        maybe_borrow!(for<'x> |ptr| -> () {
+                            drop(Reborrow::reborrow(&mut ptr));
+                            fail();
+                        });
+                    }));
+                    assert!(result.is_err());
+                    1
+                }
+                with_pointer(check);
+            }
+
+            $($extra)*
+        }
+    };
+}
+
+pub use reborrow_conformance_tests;
+
+// MARK: Internal
+
+/// Separates the shorter lifetime, which must come last in the binder, from the pointer lifetimes.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __split_bounded_lifetimes {
+    (
+        ($mode:ident $exit:ident) [$($lt:lifetime)*] [$next:lifetime $($rest_lt:lifetime)+]
+        $bounds:tt $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            ($mode $exit) [$($lt)* $next] [$($rest_lt)+] $bounds $($rest)*
+        }
+    };
+    (($mode:ident $exit:ident) [] [$short:lifetime] $bounds:tt $($rest:tt)*) => {
+        $crate::_m::compile_error!(
+            "a lifetime with bounds must be preceded by the lifetimes of the pointers"
+        )
+    };
+    (
+        ($mode:ident $exit:ident) [$($lt:lifetime)+] [$short:lifetime]
+        [$($long:lifetime: $shorter:lifetime),+] $($rest:tt)*
+    ) => {
+        $crate::_m::__parse_pointers! {
+            @arg ($mode [$($lt)+ where $short [$($long: $shorter),+]] $exit) [] [] []
+            $($rest)*
+        }
+    };
+}
+
+pub use __split_bounded_lifetimes;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_pointers {
+    // Expecting the next entry in the pointer list:
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @end $cx $ptrs $carry $bindings $($rest)* }
+    };
+    (@arg $cx:tt $ptrs:tt [$($carry:ident)*] $bindings:tt move $new:ident $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @sep $cx $ptrs [$($carry)* $new] $bindings $($rest)* }
+    };
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt $ptr:ident : $Ptr:ty = $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @init $cx $ptrs $carry $bindings ($ptr: $Ptr) [] $($rest)* }
+    };
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt $ptr:ident : $Ptr:ty $(, $($rest:tt)*)?) => {
+        $crate::_m::__parse_pointers! {
+            @init $cx $ptrs $carry $bindings ($ptr: $Ptr) [$ptr] $(, $($rest)*)?
+        }
+    };
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt $ptr:ident : $Ptr:ty | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @init $cx $ptrs $carry $bindings ($ptr: $Ptr) [$ptr] | $($rest)* }
+    };
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt $ptr:ident = $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @init $cx $ptrs $carry $bindings ($ptr) [] $($rest)* }
+    };
+    (@arg $cx:tt [$($ptrs:ident)*] $carry:tt $bindings:tt $ptr:ident $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @sep $cx [$($ptrs)* $ptr] $carry $bindings $($rest)* }
+    };
+    (@arg $cx:tt $ptrs:tt $carry:tt $bindings:tt $other:tt $($rest:tt)*) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "expected a pointer or `move` followed by a value to carry, found `",
+            $crate::_m::stringify!($other),
+            "`",
+        ))
+    };
+
+    // Expecting the end of an entry:
+    (@sep $cx:tt $ptrs:tt $carry:tt $bindings:tt , $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @arg $cx $ptrs $carry $bindings $($rest)* }
+    };
+    (@sep $cx:tt $ptrs:tt $carry:tt $bindings:tt | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! { @end $cx $ptrs $carry $bindings $($rest)* }
+    };
+    (@sep $cx:tt $ptrs:tt $carry:tt $bindings:tt $other:tt $($rest:tt)*) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "expected `,` or `|`, found `",
+            $crate::_m::stringify!($other),
+            "`",
+        ))
+    };
+
+    // Collecting the tokens of a pointer's initializer up to the next `,` or `|`:
+    (
+        @init $cx:tt [$($ptrs:ident)*] $carry:tt [$($bindings:tt)*]
+        ($ptr:ident $(: $Ptr:ty)?) [$($init:tt)+] $(, $($rest:tt)*)?
+    ) => {
+        $crate::_m::__parse_pointers! {
+            @sep $cx [$($ptrs)* $ptr] $carry [$($bindings)* ($ptr $(: $Ptr)? = $($init)+)]
+            $(, $($rest)*)?
+        }
+    };
+    (
+        @init $cx:tt [$($ptrs:ident)*] $carry:tt [$($bindings:tt)*]
+        ($ptr:ident $(: $Ptr:ty)?) [$($init:tt)+] | $($rest:tt)*
+    ) => {
+        $crate::_m::__parse_pointers! {
+            @sep $cx [$($ptrs)* $ptr] $carry [$($bindings)* ($ptr $(: $Ptr)? = $($init)+)]
+            | $($rest)*
+        }
+    };
+    (@init $cx:tt $ptrs:tt $carry:tt $bindings:tt $binding:tt [$($init:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @init $cx $ptrs $carry $bindings $binding [$($init)* $next] $($rest)*
+        }
+    };
+
+    // Finished parsing the pointer list:
+    (@end ($mode:ident $lt:tt $exit:ident) [] $carry:tt $bindings:tt $($rest:tt)*) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "at least one pointer must be passed to ",
+            $crate::_m::stringify!($mode),
+            "!",
+        ))
+    };
+    (@end ($mode:ident $lt:tt $exit:ident) $ptrs:tt $carry:tt $bindings:tt -> _ $($rest:tt)*) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "the return type of ",
+            $crate::_m::stringify!($mode),
+            "! must name the lifetimes that borrowed data is returned at; `_` can only be used ",
+            "for parts of the type that don't borrow from the pointers",
+        ))
+    };
+    (
+        @end (maybe_borrow_match $lt:tt $exit:ident) $ptrs:tt $carry:tt $bindings:tt
+        -> $Ret:ty { match $($body:tt)* } $(,)?
+    ) => {
+        $crate::_m::__maybe_borrow_match! {
+            @scrutinee ($lt $exit $ptrs $carry $bindings $Ret) [] $($body)*
+        }
+    };
+    (
+        @end (maybe_borrow $lt:tt $exit:ident) $ptrs:tt $carry:tt []
+        -> $Ret:ty $block:block produce $($produce:tt)+
+    ) => {
+        $crate::_m::__maybe_borrow_produce! {
+            $exit $ptrs $carry $lt $Ret, $block produce $($produce)+
+        }
+    };
+    (
+        @end (maybe_borrow $lt:tt $exit:ident) $ptrs:tt $carry:tt
+        [$(($ptr:ident $(: $Ptr:ty)? = $($init:tt)+))+]
+        -> $Ret:ty $block:block produce $($produce:tt)+
+    ) => {
+        $(let mut $ptr $(: $Ptr)? = $($init)+;)+
+        $crate::_m::__maybe_borrow_produce!(
+            $exit $ptrs $carry $lt $Ret, $block produce $($produce)+
+        )
+    };
+    // The invocation is an expression, so the coerced pointers are scoped to it.
+    (
+        @end (maybe_borrow_expr $lt:tt $exit:ident) $ptrs:tt $carry:tt
+        [$(($ptr:ident $(: $Ptr:ty)? = $($init:tt)+))+]
+        -> $Ret:ty $block:block $($tail:tt)*
+    ) => {{
+        $(let mut $ptr $(: $Ptr)? = $($init)+;)+
+        $crate::_m::__maybe_borrow_carried!(
+            maybe_borrow_expr $exit $ptrs $carry $lt $Ret, $block $($tail)*
+        )
+    }};
+    (
+        @end ($mode:ident $lt:tt $exit:ident) $ptrs:tt $carry:tt []
+        -> $Ret:ty $(as $Body:ty)? $block:block $($tail:tt)*
+    ) => {
+        $crate::_m::__maybe_borrow_carried! {
+            $mode $exit $ptrs $carry $lt $Ret $(as $Body)?, $block $($tail)*
+        }
+    };
+    (
+        @end ($mode:ident $lt:tt $exit:ident) $ptrs:tt $carry:tt
+        [$(($ptr:ident $(: $Ptr:ty)? = $($init:tt)+))+]
+        -> $Ret:ty $(as $Body:ty)? $block:block $($tail:tt)*
+    ) => {
+        $(let mut $ptr $(: $Ptr)? = $($init)+;)+
+        $crate::_m::__maybe_borrow_carried!(
+            $mode $exit $ptrs $carry $lt $Ret $(as $Body)?, $block $($tail)*
+        )
+    };
+    (@end ($mode:ident $lt:tt $exit:ident) $ptrs:tt $carry:tt $bindings:tt $($rest:tt)*) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "Explicit return type required in ",
+            $crate::_m::stringify!($mode),
+            "!",
+        ))
+    };
+}
+
+pub use __parse_pointers;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __maybe_borrow_match {
+    // Collecting the scrutinee up to the braces containing the arms:
+    (@scrutinee $cx:tt [$($scrutinee:tt)*] { $($arms:tt)* }) => {
+        $crate::_m::__maybe_borrow_match! { @arms $cx [$($scrutinee)*] [] $($arms)* }
+    };
+    (@scrutinee $cx:tt [$($scrutinee:tt)*] $next:tt $($rest:tt)+) => {
+        $crate::_m::__maybe_borrow_match! { @scrutinee $cx [$($scrutinee)* $next] $($rest)+ }
+    };
+
+    // Translating each arm:
+    (
+        @arms $cx:tt $scrutinee:tt [$($out:tt)*]
+        $pat:pat $(if $guard:expr)? => continue $(, $($rest:tt)*)?
+    ) => {
+        $crate::_m::__maybe_borrow_match! {
+            @arms $cx $scrutinee [$($out)* $pat $(if $guard)? => {},] $($($rest)*)?
+        }
+    };
+    (
+        @arms $cx:tt $scrutinee:tt [$($out:tt)*]
+        $pat:pat $(if $guard:expr)? => $kind:ident $body:expr $(, $($rest:tt)*)?
+    ) => {
+        $crate::_m::__maybe_borrow_match! {
+            @arms $cx $scrutinee [
+                $($out)* $pat $(if $guard)? => $crate::_m::__maybe_borrow_match!(@arm $kind $body),
+            ] $($($rest)*)?
+        }
+    };
+    (
+        @arms $cx:tt $scrutinee:tt [$($out:tt)*]
+        $pat:pat $(if $guard:expr)? => $kind:ident $body:block $($rest:tt)*
+    ) => {
+        $crate::_m::__maybe_borrow_match! {
+            @arms $cx $scrutinee [
+                $($out)* $pat $(if $guard)? => $crate::_m::__maybe_borrow_match!(@arm $kind $body),
+            ] $($rest)*
+        }
+    };
+    (@arms $cx:tt $scrutinee:tt $out:tt $($rest:tt)+) => {
+        $crate::_m::compile_error!(
+            "expected match arms of the form `pattern => borrow expr` or `pattern => continue expr`"
+        )
+    };
+    (
+        @arms ($lt:tt $exit:ident $ptrs:tt $carry:tt $bindings:tt $Ret:ty)
+        [$($scrutinee:tt)*] [$($out:tt)*]
+    ) => {
+        $crate::_m::__parse_pointers! {
+            @end (maybe_borrow $lt $exit) $ptrs $carry $bindings -> $Ret {
+                match $($scrutinee)* {
+                    $($out)*
+                }
+            }
+        }
+    };
+
+    (@arm borrow $body:expr) => {
+        return_borrowed!($body)
+    };
+    (@arm continue $body:expr) => {
+        $body
+    };
+    (@arm $other:ident $body:expr) => {
+        $crate::_m::compile_error!($crate::_m::concat!(
+            "expected `borrow` or `continue` after `=>`, found `",
+            $crate::_m::stringify!($other),
+            "`",
+        ))
+    };
+}
+
+pub use __maybe_borrow_match;
+
+#[cfg(not(maybe_borrow_polonius))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __maybe_borrow_carried {
+    (maybe_borrow $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block $(,)?) => {
+        $crate::_m::__maybe_borrow_carried! { @block $exit $ptrs $carry $lt $Ret, $block }
+    };
+    (
+        maybe_borrow_expr $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block
+        else |$value:pat_param| $restored:expr $(,)?
+    ) => {
+        $crate::_m::__maybe_borrow_carried! {
+            @block (outcome |$value| $restored) $ptrs $carry $lt $Ret, $block
+        }
+    };
+    (maybe_borrow_expr $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block $(,)?) => {
+        $crate::_m::__maybe_borrow_carried! {
+            @block (outcome |_value| _value) $ptrs $carry $lt $Ret, $block
+        }
+    };
+    (@block $exit:tt [$($ptr:ident)+] $carry:tt $lt:tt $Ret:ty, $block:block) => {
+        $crate::_m::__maybe_borrow! {
+            $exit $carry,
+            $Ret,
+            $lt,
+            |[$($ptr)+]| {
+                $crate::_m::__define_continue_with! { ($) $lt [$($ptr)+] $carry [$($ptr)+] }
+                $crate::_m::ControlFlow::Continue((
+                    $crate::_m::__import_contextual_macros! { __return_borrowed, $block },
+                    $crate::_m::__nest_pattern!(@input <- $carry),
+                ))
+            }
+        }
+    };
+    (
+        try_maybe_borrow $exit:ident [$($ptr:ident)+] $carry:tt $lt:tt $Ret:ty as $Body:ty,
+        $block:block
+        $(else $error:expr)? $(,)?
+    ) => {
+        $crate::_m::__maybe_borrow! {
+            $exit $carry,
+            $Ret,
+            $lt,
+            |[$($ptr)+]| {
+                $crate::_m::__define_continue_with! { ($) unsupported in try_maybe_borrow }
+                $crate::_m::try_maybe_borrow_as_helper::<$Body, _, _, _>(
+                    |w| w.wrap((
+                        $crate::_m::__import_contextual_macros! { __return_borrowed_try_as, $block },
+                        $crate::_m::__nest_pattern!(@input <- $carry),
+                    )),
+                    $crate::_m::__maybe_borrow_carried!(@convert $($error)?),
+                )
+            }
+        }
+    };
+    (@convert) => {
+        $crate::_m::FromOtherResidual::from_other_residual
+    };
+    (@convert $error:expr) => {
+        |_| $crate::_m::from_error($error)
+    };
+    (try_maybe_borrow $exit:ident [$($ptr:ident)+] $carry:tt $lt:tt $Ret:ty, $block:block $(,)?) => {
+        $crate::_m::__maybe_borrow! {
+            $exit $carry,
+            $Ret,
+            $lt,
+            |[$($ptr)+]| {
+                $crate::_m::__define_continue_with! { ($) unsupported in try_maybe_borrow }
+                $crate::_m::try_maybe_borrow_helper(|w| w.wrap((
+                    $crate::_m::__import_contextual_macros! { __return_borrowed_try, $block },
+                    $crate::_m::__nest_pattern!(@input <- $carry),
+                )))
+            }
+        }
+    };
+}
+
+// With `cfg(maybe_borrow_polonius)`, the borrow checker accepts returning borrowed data
+// conditionally, so the block runs in place and exits with `return` and `break` instead.
+#[cfg(maybe_borrow_polonius)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __maybe_borrow_carried {
+    (maybe_borrow $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block $(,)?) => {
+        $crate::_m::__maybe_borrow_carried! { @native ($) $exit $ptrs $carry, $block }
+    };
+    (
+        maybe_borrow_expr $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block
+        else |$value:pat_param| $restored:expr $(,)?
+    ) => {
+        $crate::_m::__maybe_borrow_carried! {
+            @native ($) (outcome |$value| $restored) $ptrs $carry, $block
+        }
+    };
+    (maybe_borrow_expr $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block $(,)?) => {
+        $crate::_m::__maybe_borrow_carried! {
+            @native ($) (outcome |_value| _value) $ptrs $carry, $block
+        }
+    };
+    // `?` needs a scope of its own here, so the block still runs in a closure, which takes the
+    // pointers by value rather than reborrowing them.
+    (
+        try_maybe_borrow $exit:ident [$($ptr:ident)+] $carry:tt $lt:tt $Ret:ty as $Body:ty,
+        $block:block
+        $(else $error:expr)? $(,)?
+    ) => {
+        match $crate::_m::try_maybe_borrow_as_native::<$Body, _, _, _>(
+            (
+                $crate::_m::__nest_pattern!(@input <- [$($ptr)+]),
+                $crate::_m::__nest_pattern!(@input <- $carry),
+            ),
+            |(
+                $crate::_m::__nest_pattern!(@mut <- [$($ptr)+]),
+                $crate::_m::__nest_pattern!(@mut <- $carry),
+            )| {
+                let _ = $crate::_m::__nest_pattern!(@noop_use_mut <- [$($ptr)+]);
+                let _ = $crate::_m::__nest_pattern!(@noop_use_mut <- $carry);
+                $crate::_m::__define_continue_with! { ($) unsupported in try_maybe_borrow }
+                $crate::_m::CustomTry::from_continue($crate::_m::ControlFlow::Continue((
+                    $crate::_m::__import_contextual_macros! { __return_borrowed_try_as, $block },
+                    (
+                        $crate::_m::__nest_pattern!(@input <- [$($ptr)+]),
+                        $crate::_m::__nest_pattern!(@input <- $carry),
+                    ),
+                )))
+            },
+            $crate::_m::__maybe_borrow_carried!(@convert $($error)?),
+        ) {
+            $crate::_m::ControlFlow::Break(_ret) => {
+                $crate::_m::__maybe_borrow_carried!(@exit $exit _ret)
+            }
+            $crate::_m::ControlFlow::Continue((_out, (_ptrs, _carried))) => {
+                #[allow(unused_assignments)]
+                {
+                    $crate::_m::__pointer_assign! { @final [$($ptr)+] <- _ptrs }
+                    $crate::_m::__pointer_assign! { @final $carry <- _carried }
+                }
+                _out
+            }
+        }
+    };
+    (@convert) => {
+        $crate::_m::FromOtherResidual::from_other_residual
+    };
+    (@convert $error:expr) => {
+        |_| $crate::_m::from_error($error)
+    };
+    (
+        try_maybe_borrow $exit:ident [$($ptr:ident)+] [$($carry:ident)*] $lt:tt $Ret:ty,
+        $block:block $(,)?
+    ) => {{
+        $($crate::_m::noop_use_mut(&mut $ptr);)+
+        $($crate::_m::noop_use_mut(&mut $carry);)*
+        $crate::_m::__define_continue_with! { ($) unsupported in try_maybe_borrow }
+        $crate::_m::__maybe_borrow_carried! { @import $exit, $block }
+    }};
+    (@exit return $value:ident) => {
+        return $value
+    };
+    // Nested inside another block, the value is returned with that block's `return_borrowed!`.
+    (@exit propagate $value:ident) => {
+        return_borrowed!($value)
+    };
+    (@import return, $block:block) => {
+        $crate::_m::__import_contextual_macros! { __return_borrowed_native, $block }
+    };
+    // Nested inside another block, that block's `return_borrowed!` is used.
+    (@import propagate, $block:block) => {
+        $crate::_m::__import_contextual_macros! { , $block }
+    };
+    (@native ($d:tt) $exit:ident [$($ptr:ident)+] [$($carry:ident)*], $block:block) => {{
+        $($crate::_m::noop_use_mut(&mut $ptr);)+
+        $($crate::_m::noop_use_mut(&mut $carry);)*
+        // The block may end with `return_borrowed!` or `continue_with!`.
+        #[allow(unused_labels, unreachable_code)]
+        let _out = '__maybe_borrow: {
+            #[allow(unused_macros)]
+            macro_rules! __maybe_borrow_break {
+                ($d value:expr) => {
+                    break '__maybe_borrow $d value
+                };
+            }
+            $crate::_m::__maybe_borrow_carried! { @continue_with ($d) [$($ptr)+] }
+            $crate::_m::__maybe_borrow_carried! { @import $exit, $block }
+        };
+        _out
+    }};
+    (
+        @native ($d:tt) (outcome |$value:pat_param| $restored:expr)
+        [$($ptr:ident)+] [$($carry:ident)*], $block:block
+    ) => {{
+        $($crate::_m::noop_use_mut(&mut $ptr);)+
+        $($crate::_m::noop_use_mut(&mut $carry);)*
+        #[allow(unused_labels, unreachable_code)]
+        let _out = '__maybe_borrow_outcome: {
+            let _value = '__maybe_borrow: {
+                #[allow(unused_macros)]
+                macro_rules! __maybe_borrow_break {
+                    ($d value:expr) => {
+                        break '__maybe_borrow $d value
+                    };
+                }
+                #[allow(unused_macros)]
+                macro_rules! return_borrowed {
+                    ($d value:expr $d(,)?) => {
+                        break '__maybe_borrow_outcome $crate::Outcome::Borrowed($d value)
+                    };
+                }
+                $crate::_m::__maybe_borrow_carried! { @continue_with ($d) [$($ptr)+] }
+                $crate::_m::__import_contextual_macros! { , $block }
+            };
+            let $value = _value;
+            $crate::Outcome::Restored($restored)
+        };
+        _out
+    }};
+    (@continue_with ($d:tt) [$($ptr:ident)+]) => {
+        #[allow(unused_macros)]
+        macro_rules! continue_with {
+            $(
+                ($ptr, $d new_ptr:expr $d(,)?) => {
+                    continue_with!($ptr, $d new_ptr, ())
+                };
+                ($ptr, $d new_ptr:expr, $d value:expr $d(,)?) => {{
+                    let _new_ptr = $d new_ptr;
+                    let _value = $d value;
+                    $ptr = _new_ptr;
+                    __maybe_borrow_break!(_value)
+                }};
+            )+
+            ($d other:ident, $d($d _:tt)*) => {
+                $crate::_m::compile_error!($crate::_m::concat!(
+                    "`",
+                    $crate::_m::stringify!($d other),
+                    "` is not one of the pointers passed to maybe_borrow!",
+                ))
+            };
+        }
+    };
+}
+
+pub use __maybe_borrow_carried;
 
-/// Runs the given block, either returning a value borrowing data from `$ptr`'s target, or
-/// continuing by evaluating to a non-borrowing value.
+/// Runs a `maybe_borrow!` block that has a `produce` arm.
 ///
-#[cfg(not(doc))]
-#[doc = include_str!("./maybe_borrow.md")]
+/// The block runs on short reborrows of the pointers, so nothing is extended. If it exits with
+/// `produce!`, the arm is evaluated on the pointers themselves, and its value is returned.
+/// Values carried with `move` are moved into the block, and handed back if it continues.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! maybe_borrow {
-    ($(for<$($lt:lifetime),* $(,)?>)? |$($ptr:ident),+ $(,)?| -> $Ret:ty $block:block $(,)?) => {{
-        $crate::_m::__maybe_borrow! {
-            $Ret,
-            [$($($lt)*)?],
-            |[$($ptr)+]| { $crate::_m::ControlFlow::Continue({
-                $crate::_m::__import_contextual_macros! { __return_borrowed, $block }
-            }) }
+macro_rules! __maybe_borrow_produce {
+    (
+        $exit:ident [$($ptr:ident)+] $carry:tt $lt:tt $Ret:ty, $block:block
+        produce |$value:pat_param| $produce:expr $(,)?
+    ) => {{
+        $crate::_m::__check_lifetime_bounds! { $lt }
+        let _lifetimes = $crate::_m::__maybe_borrow_produce!(@lifetimes $lt [$($ptr)+]);
+        match $crate::_m::maybe_borrow_produce(
+            (
+                ($($crate::Reborrow::reborrow(&mut $ptr),)+),
+                $crate::_m::__nest_pattern!(@input <- $carry),
+            ),
+            |(($(mut $ptr,)+), $crate::_m::__nest_pattern!(@mut <- $carry))| {
+                $($crate::_m::noop_use_mut(&mut $ptr);)+
+                let _ = $crate::_m::__nest_pattern!(@noop_use_mut <- $carry);
+                // The block may end with `produce!`.
+                #[allow(unreachable_code)]
+                return $crate::_m::Continue((
+                    $crate::_m::__import_contextual_macros! {
+                        __return_borrowed_produce,
+                        {
+                            #[allow(unused)]
+                            use $crate::_m::__produce as produce;
+                            $block
+                        }
+                    },
+                    $crate::_m::__nest_pattern!(@input <- $carry),
+                ));
+            },
+        ) {
+            $crate::_m::Continue((_out, _carried)) => {
+                #[allow(unused_assignments)]
+                {
+                    $crate::_m::__pointer_assign! { @final $carry <- _carried }
+                }
+                _out
+            }
+            $crate::_m::Break($crate::_m::ProduceExit::Return(_ret)) => {
+                $crate::_m::__maybe_borrow_produce!(@exit $exit _lifetimes $lt $Ret, _ret)
+            }
+            $crate::_m::Break($crate::_m::ProduceExit::Produce(_value)) => {
+                // Moved, so that a closure this is nested in captures the pointers by value
+                // rather than returning a borrow of its captures.
+                $(
+                    let mut $ptr = $ptr;
+                    $crate::_m::noop_use_mut(&mut $ptr);
+                )+
+                let $value = _value;
+                let _ret = $produce;
+                $crate::_m::__maybe_borrow_produce!(@exit $exit _lifetimes $lt $Ret, _ret)
+            }
         }
     }};
+    (
+        $exit:ident $ptrs:tt $carry:tt $lt:tt $Ret:ty, $block:block
+        produce $produce:expr $(,)?
+    ) => {
+        $crate::_m::__maybe_borrow_produce! {
+            $exit $ptrs $carry $lt $Ret, $block produce |()| $produce
+        }
+    };
+
+    // The lifetime of each pointer, or of the last few if they share the last lifetime.
+    (@lifetimes [$($lt:lifetime)+ where $($bounds:tt)*] $ptrs:tt) => {
+        $crate::_m::__maybe_borrow_produce!(@lifetimes [$($lt)+] $ptrs)
+    };
+    (@lifetimes [] $ptrs:tt) => {
+        ()
+    };
+    (@lifetimes [$lt0:lifetime] [$ptr0:ident $($ptr:ident)*]) => {
+        (
+            $crate::_m::PointerLifetime::of(&$ptr0)$(.and(&$ptr))*,
+            (),
+        )
+    };
+    (@lifetimes [$lt0:lifetime $($lt:lifetime)+] [$ptr0:ident $($ptr:ident)+]) => {
+        (
+            $crate::_m::PointerLifetime::of(&$ptr0),
+            $crate::_m::__maybe_borrow_produce!(@lifetimes [$($lt)+] [$($ptr)+]),
+        )
+    };
 
-    ($(for<$lt:lifetime $(,)?>)? |$ptr:ident $(,)?| $block:expr $(,)?) => {
-        $crate::_m::compile_error!("Explicit return type required in maybe_borrow!");
-    }
+    // The value must have the return type at the lifetime of each pointer. The other lifetimes,
+    // and the shorter lifetime of bounded lifetimes, are left to inference.
+    (@check $value:ident $lifetimes:ident [] $Ret:ty) => {
+        let $value: $Ret = $value;
+    };
+    (@check $value:ident $lifetimes:ident [$($lt:lifetime)+] $Ret:ty) => {
+        $crate::_m::__maybe_borrow_produce! { @check_each $value $lifetimes [$($lt)+] [] $Ret }
+    };
+    (
+        @check $value:ident $lifetimes:ident
+        [$($lt:lifetime)+ where $short:lifetime $bounds:tt] $Ret:ty
+    ) => {
+        $crate::_m::__maybe_borrow_produce! {
+            @check_each $value $lifetimes [$($lt)+] [$short] $Ret
+        }
+    };
+    (
+        @check_each $value:ident $lifetimes:ident
+        [$lt0:lifetime $($lt:lifetime)*] [$($past_lt:lifetime)*] $Ret:ty
+    ) => {
+        let (_lifetime, _lifetimes) = $lifetimes;
+        let $value = _lifetime.check::<$crate::_m::WithLt![
+            $lt0 -> $crate::_m::__actual_combined_with_lt![$($past_lt)* $($lt)* => $Ret]
+        ]>($value);
+        $crate::_m::__maybe_borrow_produce! {
+            @check_each $value _lifetimes [$($lt)*] [$($past_lt)* $lt0] $Ret
+        }
+    };
+    (@check_each $value:ident $lifetimes:ident [] $past_lt:tt $Ret:ty) => {};
 
+    (@exit $exit:ident $lifetimes:ident $lt:tt $Ret:ty, $value:ident) => {{
+        $crate::_m::__maybe_borrow_produce! { @check $value $lifetimes $lt $Ret }
+        $crate::_m::__maybe_borrow_produce!(@exit $exit $value)
+    }};
+    (@exit return $value:ident) => {
+        return $value
+    };
+    // Nested inside another block, the value is returned with that block's `return_borrowed!`.
+    (@exit propagate $value:ident) => {
+        return_borrowed!($value)
+    };
 }
 
-pub use maybe_borrow;
+pub use __maybe_borrow_produce;
 
-#[cfg(not(doc))]
-#[doc = include_str!("./try_maybe_borrow.md")]
+/// Checks the bounds of lifetimes declared with bounds (`for<'x, 'z where 'x: 'z>`), and does
+/// nothing for other lifetimes.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! try_maybe_borrow {
-    ($(for<$lt:lifetime $(,)?>)? |$($ptr:ident),+ $(,)?| -> $Ret:ty $block:block $(,)?) => {
-        $crate::_m::__maybe_borrow! {
-            $Ret,
-            [$($lt)?],
-            |[$($ptr)+]| { $crate::_m::try_maybe_borrow_helper(|w| w.wrap(
-                $crate::_m::__import_contextual_macros! { __return_borrowed_try, $block }
-            )) }
+macro_rules! __check_lifetime_bounds {
+    ([$($lt:lifetime)+ where $short:lifetime [$($long:lifetime: $shorter:lifetime),+]]) => {
+        // Every bound must be on the shorter lifetime.
+        let _ = $crate::_m::PhantomData::<$crate::_m::WithLt![$short -> ($(&$shorter (),)+)]>;
+        // Every bound must be from a pointer lifetime, and every pointer lifetime must be
+        // bounded, since the return type may borrow from any of the pointers.
+        fn _bounds_from_pointer_lifetimes<$($lt),+>() {
+            let _: ($(&$long (),)+);
+        }
+        fn _pointer_lifetimes_bounded<$($long),+>() {
+            let _: ($(&$lt (),)+);
         }
     };
-
-    ($(for<$lt:lifetime $(,)?>)? |$ptr:ident $(,)?| $block:expr $(,)?) => {
-        $crate::_m::compile_error!("Explicit return type required in try_maybe_borrow!");
-    }
+    ($lt:tt) => {};
 }
 
-pub use try_maybe_borrow;
-
-// MARK: Internal
+pub use __check_lifetime_bounds;
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __actual_combined_with_lt {
     (=> $Ret:ty) => { $Ret };
+    // The lifetimes of the other pointers are replaced with a shorter lifetime they all outlive.
+    (@bounded $short:lifetime; $lt0:lifetime $($lt:lifetime)* => $Ret:ty) => {
+        $crate::_m::Actual<
+            $short,
+            $crate::_m::WithLt![
+                $lt0 -> $crate::_m::__actual_combined_with_lt![@bounded $short; $($lt)* => $Ret]
+            ]
+        >
+    };
+    (@bounded $short:lifetime; => $Ret:ty) => { $Ret };
     ($lt0:lifetime $($lt:lifetime)* => $Ret:ty) => {
         $crate::_m::Actual<
             $crate::_m::WithLt![
@@ -111,43 +1273,277 @@ pub use __actual_combined_with_lt;
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __import_contextual_macros {
-    ($return_borrowed:ident, $block:expr) => {{
+    ($($return_borrowed:ident)?, $block:expr) => {{
         #[allow(unused)]
         use $crate::_m::__ready as ready;
         #[allow(unused)]
-        use $crate::_m::$return_borrowed as return_borrowed;
+        use $crate::_m::__ready_some as ready_some;
+        #[allow(unused)]
+        use $crate::_m::__try_ready as try_ready;
+        #[allow(unused)]
+        use $crate::_m::__return_ready as return_ready;
+        #[allow(unused)]
+        use $crate::_m::__nested_maybe_borrow as maybe_borrow;
+        #[allow(unused)]
+        use $crate::_m::__nested_try_maybe_borrow as try_maybe_borrow;
+        #[allow(unused)]
+        use $crate::_m::__nested_maybe_borrow_match as maybe_borrow_match;
+        $(
+            #[allow(unused)]
+            use $crate::_m::$return_borrowed as return_borrowed;
+        )?
         $block
     }};
 }
 
 pub use __import_contextual_macros;
 
+/// Used in place of [`maybe_borrow!`] inside another invocation's block, so that borrowed data
+/// is forwarded through that block's closure rather than returned from it directly.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nested_maybe_borrow {
+    (
+        for<$($lt:lifetime),+ where $($long:lifetime: $short:lifetime),+ $(,)?>
+        | $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            (maybe_borrow propagate) [] [$($lt)*] [$($long: $short),+] $($rest)*
+        }
+    };
+    ($(for<$($lt:lifetime),* $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (maybe_borrow [$($($lt)*)?] propagate) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use __nested_maybe_borrow;
+
+/// Used in place of [`try_maybe_borrow!`] inside another invocation's block.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nested_try_maybe_borrow {
+    ($(for<$lt:lifetime $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (try_maybe_borrow [$($lt)?] propagate) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use __nested_try_maybe_borrow;
+
+/// Used in place of [`maybe_borrow_match!`] inside another invocation's block.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nested_maybe_borrow_match {
+    (
+        for<$($lt:lifetime),+ where $($long:lifetime: $short:lifetime),+ $(,)?>
+        | $($rest:tt)*
+    ) => {
+        $crate::_m::__split_bounded_lifetimes! {
+            (maybe_borrow_match propagate) [] [$($lt)*] [$($long: $short),+] $($rest)*
+        }
+    };
+    ($(for<$($lt:lifetime),* $(,)?>)? | $($rest:tt)*) => {
+        $crate::_m::__parse_pointers! {
+            @arg (maybe_borrow_match [$($($lt)*)?] propagate) [] [] [] $($rest)*
+        }
+    };
+}
+
+pub use __nested_maybe_borrow_match;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_continue_with {
+    // Every pointer shares a single lifetime, so they're all reborrowed by the same call and can
+    // be replaced together.
+    (($d:tt) [$($lt:lifetime)? $(where $($bounds:tt)*)?] $all_ptrs:tt $carry:tt [$($ptr:ident)+]) => {
+        #[allow(unused_macros)]
+        macro_rules! continue_with {
+            $(
+                ($ptr, $d new_ptr:expr $d(,)?) => {
+                    continue_with!($ptr, $d new_ptr, ())
+                };
+                ($ptr, $d new_ptr:expr, $d value:expr $d(,)?) => {{
+                    let _new_ptr = $d new_ptr;
+                    let _value = $d value;
+                    let $ptr = _new_ptr;
+                    return $crate::_m::ControlFlow::Break($crate::_m::EarlyExit::Replace(
+                        (
+                            $crate::_m::__nest_pattern!(@input <- $all_ptrs),
+                            $crate::_m::restore_replaced,
+                        ),
+                        (_value, $crate::_m::__nest_pattern!(@input <- $carry)),
+                    ));
+                }};
+            )+
+            ($d other:ident, $d($d _:tt)*) => {
+                $crate::_m::compile_error!($crate::_m::concat!(
+                    "`",
+                    $crate::_m::stringify!($d other),
+                    "` is not one of the pointers passed to maybe_borrow!",
+                ))
+            };
+        }
+    };
+    (($d:tt) [$($lt:lifetime)* $(where $($bounds:tt)*)?] $all_ptrs:tt $carry:tt $ptrs:tt) => {
+        $crate::_m::__define_continue_with! { ($d) unsupported with multiple lifetimes }
+    };
+    (($d:tt) unsupported $($reason:tt)+) => {
+        #[allow(unused_macros)]
+        macro_rules! continue_with {
+            ($d($d _:tt)*) => {
+                $crate::_m::compile_error!($crate::_m::concat!(
+                    "continue_with! is not supported ",
+                    $crate::_m::stringify!($($reason)+),
+                ))
+            };
+        }
+    };
+}
+
+pub use __define_continue_with;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __maybe_borrow {
-    ($Ret:ty, $lt:tt, |$ptr:tt| $block:block) => {{
-        let _pairs = match $crate::_m::__maybe_borrow_nested! {
-            $Ret, [], $lt, [], |$ptr| $block
+    (
+        @run $exit:tt $carry:tt, $Ret:ty, $nested_lt:tt, $lt:tt, |$ptr:tt| $block:block
+    ) => {{
+        match $crate::_m::__maybe_borrow_nested! {
+            $Ret, [], $nested_lt, [], |$ptr| $block
         } {
-            $crate::_m::ControlFlow::Break(_ret) => return _ret,
-            $crate::_m::ControlFlow::Continue(_pairs) => _pairs,
-        };
+            $crate::_m::ControlFlow::Break(_ret) => $crate::_m::__maybe_borrow_exit!($exit borrowed _ret),
+            $crate::_m::ControlFlow::Continue(_pairs) => {
+                let _out;
 
-        let _out;
+                #[allow(unused_assignments)]
+                {
+                    $crate::_m::__pointer_assign! { _out $lt $ptr <- _pairs }
+                }
 
-        #[allow(unused_assignments)]
-        {
-            $crate::_m::__pointer_assign! { _out $lt $ptr <- _pairs }
+                let (_out, _carried) = _out;
+
+                #[allow(unused_assignments)]
+                {
+                    $crate::_m::__pointer_assign! { @final $carry <- _carried }
+                }
+                $crate::_m::__maybe_borrow_exit!($exit restored _out)
+            }
         }
-        _out
     }};
+    (
+        $exit:tt $carry:tt, $Ret:ty, [$($lt:lifetime)* where $($bounds:tt)*],
+        |$ptr:tt| $block:block
+    ) => {
+        $crate::_m::__maybe_borrow! {
+            @run $exit $carry, $Ret, [$($lt)* where $($bounds)*], [bounded $($lt)*], |$ptr| $block
+        }
+    };
+    ($exit:tt $carry:tt, $Ret:ty, $lt:tt, |$ptr:tt| $block:block) => {
+        $crate::_m::__maybe_borrow! { @run $exit $carry, $Ret, $lt, $lt, |$ptr| $block }
+    };
 }
 
 pub use __maybe_borrow;
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __maybe_borrow_exit {
+    (return borrowed $value:ident) => {
+        return $value
+    };
+    // Nested inside another block, the value is forwarded through that block's closure instead.
+    (propagate borrowed $value:ident) => {
+        return $crate::_m::PropagateReturn::propagate($value)
+    };
+    ((outcome $($else:tt)*) borrowed $value:ident) => {
+        $crate::Outcome::Borrowed($value)
+    };
+    ($exit:ident restored $value:ident) => {
+        $value
+    };
+    ((outcome |$pat:pat_param| $restored:expr) restored $value:ident) => {{
+        let $pat = $value;
+        $crate::Outcome::Restored($restored)
+    }};
+}
+
+pub use __maybe_borrow_exit;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __maybe_borrow_nested {
+    // Every pointer lifetime outlives a shorter lifetime that the return type may use. Each layer
+    // reborrows its own pointers for one unknown lifetime, and the pointers of every other layer
+    // for a second unknown lifetime that the first outlives.
+    (
+        $Ret:ty, [],
+        [$($lt:lifetime)+ where $short:lifetime [$($long:lifetime: $shorter:lifetime),+]], [],
+        |$ptr:tt| $block:block
+    ) => {
+        $crate::_m::__maybe_borrow_nested! {
+            @bounded $short $Ret, [], [$($lt)+], [], |$ptr| {
+                $crate::_m::__check_lifetime_bounds! {
+                    [$($lt)+ where $short [$($long: $shorter),+]]
+                }
+                $block
+            }
+        }
+    };
+    (
+        @bounded $short:lifetime $Ret:ty,
+        [$($past_lt:lifetime)*], [$lt0:lifetime],
+        [$($past_ptrs:ident)*], |$ptr:tt| $block:block
+    ) => {
+        $crate::_m::maybe_borrow_bounded::<
+            _,
+            _,
+            $crate::_m::WithLt![$lt0, $short -> $crate::_m::__actual_combined_with_lt![
+                @bounded $short; $($past_lt)* => $Ret
+            ]],
+            _,
+        >(
+            $crate::_m::__nest_pattern!(@bounded <- [$($past_ptrs)*]),
+            $crate::_m::__nest_pattern!(@input <- $ptr),
+            |
+                $crate::_m::__nest_pattern!(@mut <- $ptr),
+                $crate::_m::__nest_pattern!(@mut <- [$($past_ptrs)*]),
+                _,
+            | {
+                $($crate::_m::noop_use_mut(&mut $past_ptrs);)*
+                let _ = $crate::_m::__nest_pattern!(@noop_use_mut <- $ptr);
+                $block
+            },
+        )
+    };
+    (
+        @bounded $short:lifetime $Ret:ty,
+        [$($past_lt:lifetime)*], [$lt0:lifetime $($lt:lifetime)+],
+        [$($past_ptrs:ident)*], |[$ptr0:ident $($ptr:ident)*]| $block:block
+    ) => {
+        $crate::_m::maybe_borrow_bounded::<
+            _,
+            _,
+            $crate::_m::WithLt![$lt0, $short -> $crate::_m::__actual_combined_with_lt![
+                @bounded $short; $($past_lt)* $($lt)* => $Ret
+            ]],
+            _,
+        >(
+            $crate::_m::__nest_pattern!(@bounded <- [$($past_ptrs)* $($ptr)*]),
+            $ptr0,
+            |$ptr0, $crate::_m::__nest_pattern!(@input <- [$($past_ptrs)* $($ptr)*]), _| {
+                $crate::_m::return_bounded_nested($crate::_m::__maybe_borrow_nested! {
+                    @bounded $short $Ret,
+                    [$($past_lt)* $lt0], [$($lt)*],
+                    [$($past_ptrs)* $ptr0], |[ $($ptr)* ]| $block
+                })
+            },
+        )
+    };
+
     // No arguments remaining:
     ($Ret:ty, $past_lt:tt, $lt:tt, [$($all_ptrs:tt)*], |[]| $block:block) => {{
         $(
@@ -197,11 +1593,11 @@ macro_rules! __maybe_borrow_nested {
             $ptr0,
             |$ptr0, _| {
                 let $ptr0 = $crate::_m::ForceMove($ptr0);
-                $crate::_m::__maybe_borrow_nested! {
+                $crate::_m::return_nested($crate::_m::__maybe_borrow_nested! {
                     $Ret,
                     [$($past_lt)* $lt0], [$($lt)*],
                     [$($past_ptrs)* $ptr0], |[ $($ptr)* ]| $block
-                }
+                })
             },
         )
     };
@@ -219,9 +1615,10 @@ macro_rules! __nest_pattern {
         )
     };
     (@input <- [$arg:tt]) => { $arg };
+    (@bounded <- [$arg:tt]) => { $crate::_m::Bounded::new($arg) };
     (@mut <- [$arg:tt]) => { mut $arg };
     (@noop_use_mut <- [$arg:tt]) => { $crate::_m::noop_use_mut(&mut $arg) };
-    (@type:tt <- []) => { () };
+    (@$type:tt <- []) => { () };
 }
 
 pub use __nest_pattern;
@@ -240,6 +1637,26 @@ macro_rules! __pointer_assign {
     ( @final [] <- $value:expr) => {
         () = $value;
     };
+    ( @bounded [$ptr0:ident $($ptr:ident)+] <- $value:expr) => {
+        let _value = $value;
+        $ptr0 = _value.0.into_inner();
+        $crate::_m::__pointer_assign! { @bounded [$($ptr)*] <- _value.1 }
+    };
+    ( @bounded [$ptr0:ident] <- $value:expr) => {
+        $ptr0 = $value.into_inner();
+    };
+    // With bounded lifetimes, the pointers of every layer are handed back by the outermost one.
+    ($out:ident [bounded $lt:lifetime] $ptr:tt <- $value:expr) => {
+        let (_out, _value, ()) = $value;
+        $out = _out;
+        $crate::_m::__pointer_assign! { @final $ptr <- _value }
+    };
+    ($out:ident [bounded $($lt:lifetime)+] [$ptr0:ident $($ptr:ident)*] <- $value:expr) => {
+        let (_out, _value, _others) = $value;
+        $out = _out;
+        $ptr0 = _value;
+        $crate::_m::__pointer_assign! { @bounded [$($ptr)*] <- _others }
+    };
     ($out:ident [$($lt:lifetime)?] $ptr:tt <- $value:expr) => {
         let (_out, _value) = $value;
         $out = _out;
@@ -262,22 +1679,67 @@ pub use __pointer_assign;
 #[macro_export]
 macro_rules! __return_borrowed {
     ($value:expr $(,)?) => {
-        return $crate::_m::ControlFlow::Break($value)
+        return $crate::_m::ControlFlow::Break($crate::_m::EarlyExit::Return($value))
     };
 }
 
 pub use __return_borrowed;
 
+#[cfg(maybe_borrow_polonius)]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __return_borrowed_native {
+    ($value:expr $(,)?) => {
+        return $value
+    };
+}
+
+#[cfg(maybe_borrow_polonius)]
+pub use __return_borrowed_native;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __return_borrowed_produce {
+    ($value:expr $(,)?) => {
+        return $crate::_m::Break($crate::_m::ProduceExit::Return($value))
+    };
+}
+
+pub use __return_borrowed_produce;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __produce {
+    ($(,)?) => {
+        $crate::_m::__produce!(())
+    };
+    ($value:expr $(,)?) => {
+        return $crate::_m::Break($crate::_m::ProduceExit::Produce($value))
+    };
+}
+
+pub use __produce;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __return_borrowed_try {
     ($value:expr $(,)?) => {
-        return $crate::_m::CustomTry::map_continue($value, $crate::_m::Break)
+        return $crate::_m::FromBorrowedReturn::from_borrowed_return($value)
     };
 }
 
 pub use __return_borrowed_try;
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __return_borrowed_try_as {
+    ($value:expr $(,)?) => {
+        return $crate::_m::CustomTry::from_continue($crate::_m::ControlFlow::Break($value))
+    };
+}
+
+pub use __return_borrowed_try_as;
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __ready {
@@ -290,3 +1752,50 @@ macro_rules! __ready {
 }
 
 pub use __ready;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ready_some {
+    ($value:expr $(,)?) => {
+        match $value {
+            $crate::_m::Poll::Ready($crate::_m::Some(_value)) => _value,
+            $crate::_m::Poll::Ready($crate::_m::None) => {
+                return_borrowed!($crate::_m::Poll::Ready($crate::_m::None))
+            }
+            $crate::_m::Poll::Pending => return_borrowed!($crate::_m::Poll::Pending),
+        }
+    };
+}
+
+pub use __ready_some;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __try_ready {
+    ($value:expr $(,)?) => {
+        match $value {
+            $crate::_m::Poll::Ready($crate::_m::Some($crate::_m::Ok(_value))) => _value,
+            $crate::_m::Poll::Ready($crate::_m::Some($crate::_m::Err(_err))) => {
+                return_borrowed!($crate::_m::Poll::Ready($crate::_m::Some($crate::_m::Err(
+                    $crate::_m::From::from(_err)
+                ))))
+            }
+            $crate::_m::Poll::Ready($crate::_m::None) => {
+                return_borrowed!($crate::_m::Poll::Ready($crate::_m::None))
+            }
+            $crate::_m::Poll::Pending => return_borrowed!($crate::_m::Poll::Pending),
+        }
+    };
+}
+
+pub use __try_ready;
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __return_ready {
+    ($value:expr $(,)?) => {
+        return_borrowed!($crate::_m::Poll::Ready($value))
+    };
+}
+
+pub use __return_ready;
@@ -2,28 +2,277 @@ use core::{marker::PhantomData, mem::ManuallyDrop, ops::ControlFlow};
 
 use crate::{
     traits::*,
-    with_lt::{Actual, WithLt},
+    with_lt::{Actual, Apply, WithLt, WithLt2},
 };
 
 pub type BorrowedAs<'b, B> = <B as BorrowWithLifetime<'b>>::Pointer;
 
+/// Describes how the block passed to [`maybe_borrow`] exited early.
+pub enum EarlyExit<R, P, C> {
+    /// Return `R`, which may borrow from the pointer.
+    Return(R),
+    /// Continue with `C`, replacing the pointer with `P`, which was derived from the reborrow.
+    Replace(P, C),
+}
+
+/// Describes how a block with a `produce` arm exited early.
+pub enum ProduceExit<R, D> {
+    /// Return `R`, which can't borrow from the reborrows the block was given.
+    Return(R),
+    /// Evaluate the `produce` arm on the pointers themselves, passing it `D`.
+    Produce(D),
+}
+
+/// The pointer a block replaces `Ptr` with, derived from a reborrow for `'b`, alongside a function
+/// converting it back to `Ptr` once its lifetime has been extended to `'ptr`.
+///
+/// The function is supplied by `continue_with!`, so only blocks that replace the pointer require
+/// `Ptr` to reborrow as its own type.
+pub type Replacement<'b, 'ptr, Ptr> = (
+    BorrowedAs<'b, <Ptr as Reborrow<'ptr>>::BorrowWithLifetime>,
+    fn(BorrowedAs<'ptr, <Ptr as Reborrow<'ptr>>::BorrowWithLifetime>) -> Ptr,
+);
+
+/// Converts a value borrowed by a [`maybe_borrow`] block into the return type of the enclosing
+/// function or block.
+///
+/// This is what lets a `maybe_borrow!` nested inside another block forward its borrowed value
+/// through the enclosing block, and from there to the function.
+pub trait PropagateReturn<R> {
+    fn propagate(ret: R) -> Self;
+}
+
+impl<R> PropagateReturn<R> for R {
+    fn propagate(ret: R) -> Self {
+        ret
+    }
+}
+
+impl<R, P, C> PropagateReturn<R> for ControlFlow<EarlyExit<R, P, C>, C> {
+    fn propagate(ret: R) -> Self {
+        ControlFlow::Break(EarlyExit::Return(ret))
+    }
+}
+
+/// The value a [`maybe_borrow_expr!`](crate::maybe_borrow_expr) invocation evaluates to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Outcome<B, C> {
+    /// The block returned `B` with [`return_borrowed!`](crate::return_borrowed), which may
+    /// borrow from the pointers.
+    Borrowed(B),
+    /// The block completed and the pointers were restored, producing `C`.
+    Restored(C),
+}
+
+impl<B, C> Outcome<B, C> {
+    /// Returns the borrowed value, if there is one.
+    pub fn borrowed(self) -> Option<B> {
+        match self {
+            Self::Borrowed(b) => Some(b),
+            Self::Restored(_) => None,
+        }
+    }
+
+    /// Returns the value the block completed with, if the pointers were restored.
+    pub fn restored(self) -> Option<C> {
+        match self {
+            Self::Borrowed(_) => None,
+            Self::Restored(c) => Some(c),
+        }
+    }
+}
+
+impl<T> Outcome<T, T> {
+    /// Returns the value from either variant.
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Borrowed(t) | Self::Restored(t) => t,
+        }
+    }
+}
+
+/// Drops the wrapped pointer if the block unwinds before the guard is disarmed.
+struct UnwindGuard<Ptr>(ManuallyDrop<Ptr>);
+
+impl<Ptr> UnwindGuard<Ptr> {
+    fn disarm(self) -> ManuallyDrop<Ptr> {
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the pointer is moved out exactly once.
+        unsafe { core::ptr::read(&this.0) }
+    }
+}
+
+impl<Ptr> Drop for UnwindGuard<Ptr> {
+    fn drop(&mut self) {
+        // SAFETY: The guard is only dropped when unwinding out of the block, at which point the
+        // extended borrow has already been dropped along with the block's frame.
+        unsafe { ManuallyDrop::drop(&mut self.0) }
+    }
+}
+
+/// Runs `block` with a reborrow of `this` that has been extended to an unknown lifetime.
+///
+/// - If the block breaks, `this` is forgotten rather than dropped, because the returned value may
+///   borrow from it for all of `'ptr`.
+/// - If the block continues, `this` is handed back to the caller alongside the block's output.
+/// - If the block replaces the pointer, `this` is forgotten and the replacement is handed back to
+///   the caller in its place.
+/// - If the block panics, `this` is dropped exactly once while unwinding.
+#[allow(clippy::type_complexity)]
 pub fn maybe_borrow<'ptr, Ptr: 'ptr + Reborrow<'ptr>, B: WithLt, C>(
     this: Ptr,
     block: impl for<'unknown> FnOnce(
         BorrowedAs<'unknown, Ptr::BorrowWithLifetime>,
         PhantomData<&'unknown ()>,
-    ) -> ControlFlow<Actual<'unknown, B>, C>,
+    ) -> ControlFlow<
+        EarlyExit<Actual<'unknown, B>, Replacement<'unknown, 'ptr, Ptr>, C>,
+        C,
+    >,
 ) -> ControlFlow<Actual<'ptr, B>, (C, Ptr)> {
-    let mut this = ManuallyDrop::new(this);
+    // SAFETY: `block` is generic over the lifetime of the reborrow, so it can't let the reborrow
+    // escape other than through its return value.
+    unsafe { run_extended(this, |ptr| block(ptr, PhantomData)) }
+}
 
-    let ctrl = {
-        let erased_borrow = unsafe { Reborrow::extend(Ptr::reborrow(&mut *this)) };
+/// Runs the block of a `maybe_borrow!` invocation with a `produce` arm on `reborrows`, short
+/// reborrows of its pointers.
+///
+/// Unlike [`maybe_borrow`], nothing is extended: the block only decides whether to exit, and
+/// the `produce` arm then runs on the pointers once the reborrows are no longer used.
+/// This only exists so the block's closure gets the types of its parameters from `reborrows`.
+#[inline(always)]
+pub fn maybe_borrow_produce<P, R, D, C>(
+    reborrows: P,
+    block: impl FnOnce(P) -> ControlFlow<ProduceExit<R, D>, C>,
+) -> ControlFlow<ProduceExit<R, D>, C> {
+    block(reborrows)
+}
 
-        block(erased_borrow, PhantomData)
+/// The lifetime `'ptr` of one or more pointers passed to a `maybe_borrow!` invocation with a
+/// `produce` arm, which checks the values it returns against the return type at that lifetime.
+pub struct PointerLifetime<'ptr>(PhantomData<fn(&'ptr ()) -> &'ptr ()>);
+
+impl<'ptr> PointerLifetime<'ptr> {
+    #[inline(always)]
+    pub fn of<Ptr: 'ptr + Reborrow<'ptr>>(_: &Ptr) -> Self {
+        PointerLifetime(PhantomData)
+    }
+
+    /// Requires `ptr` to have the same lifetime, for pointers that share it.
+    #[inline(always)]
+    pub fn and<Ptr: 'ptr + Reborrow<'ptr>>(self, _: &Ptr) -> Self {
+        self
+    }
+
+    /// Passes `value` through as the type `B` maps `'ptr` to.
+    #[inline(always)]
+    pub fn check<B: ?Sized + WithLt>(self, value: Actual<'ptr, B>) -> Actual<'ptr, B> {
+        value
+    }
+}
+
+/// Like [`maybe_borrow`], but `block` may also return values borrowing for a shorter lifetime
+/// `'bound`, which is outlived by the reborrow of `this`.
+///
+/// This is used for lifetimes declared with bounds (`for<'x, 'z where 'x: 'z>`), with `outer`
+/// holding the pointers handled by enclosing and nested calls.
+/// They're reborrowed for `'bound` and passed to `block`, so that everything `block` can return
+/// at `'bound` has a lifetime it knows outlives `'bound`, and handed back if the block continues.
+/// `B` maps the pointer's lifetime and `'bound` to the returned type.
+#[allow(clippy::type_complexity)]
+pub fn maybe_borrow_bounded<'ptr: 'short, 'short, Outer, Ptr, B, C>(
+    outer: Outer,
+    this: Ptr,
+    block: impl for<'unknown, 'bound> FnOnce(
+        BorrowedAs<'unknown, Ptr::BorrowWithLifetime>,
+        BorrowedAs<'bound, Outer::BorrowWithLifetime>,
+        PhantomData<&'bound &'unknown ()>,
+    ) -> ControlFlow<
+        EarlyExit<Apply<'unknown, 'bound, B>, Replacement<'unknown, 'ptr, Ptr>, C>,
+        C,
+    >,
+) -> ControlFlow<Apply<'ptr, 'short, B>, (C, Ptr, Outer)>
+where
+    Ptr: 'ptr + Reborrow<'ptr>,
+    Outer: 'short + Reborrow<'short>,
+    B: ?Sized + WithLt2,
+{
+    let mut outer = UnwindGuard(ManuallyDrop::new(outer));
+
+    // SAFETY: `block` is generic over both lifetimes, so neither reborrow can escape other than
+    // through its return value.
+    let ctrl = unsafe {
+        run_extended(this, |ptr| {
+            let outer = Reborrow::extend(Outer::reborrow(&mut outer.0));
+            block(ptr, outer, PhantomData)
+        })
     };
 
+    // Like `this`, the other pointers are forgotten if the returned value may borrow from them,
+    // and handed back otherwise.
+    let outer = outer.disarm();
     match ctrl {
         ControlFlow::Break(out) => ControlFlow::Break(out),
+        ControlFlow::Continue((out, this)) => {
+            ControlFlow::Continue((out, this, ManuallyDrop::into_inner(outer)))
+        }
+    }
+}
+
+/// A pointer passed to [`maybe_borrow_bounded`] alongside `this`, which may be reborrowed for any
+/// lifetime that `'ptr` outlives.
+pub struct Bounded<'ptr, Ptr>(Ptr, PhantomData<&'ptr ()>);
+
+impl<'ptr, Ptr: Reborrow<'ptr>> Bounded<'ptr, Ptr> {
+    pub fn new(ptr: Ptr) -> Self {
+        Self(ptr, PhantomData)
+    }
+
+    pub fn into_inner(self) -> Ptr {
+        self.0
+    }
+}
+
+unsafe impl<'short, 'ptr: 'short, Ptr: Reborrow<'ptr>> Reborrow<'short> for Bounded<'ptr, Ptr> {
+    type BorrowWithLifetime = Ptr::BorrowWithLifetime;
+
+    fn reborrow<'b>(this: &'b mut Self) -> BorrowedAs<'b, Self::BorrowWithLifetime> {
+        Ptr::reborrow(&mut this.0)
+    }
+    unsafe fn extend<'b>(this: Self) -> BorrowedAs<'b, Self::BorrowWithLifetime> {
+        unsafe { Ptr::extend(this.0) }
+    }
+}
+
+/// Runs `block` with a reborrow of `this` that has been extended to `'ptr`.
+///
+/// ## Safety
+/// `block` must not let the reborrow, or anything derived from it, escape other than through its
+/// return value.
+#[allow(clippy::type_complexity)]
+unsafe fn run_extended<'ptr, Ptr: 'ptr + Reborrow<'ptr>, R, C>(
+    this: Ptr,
+    block: impl FnOnce(
+        BorrowedAs<'ptr, Ptr::BorrowWithLifetime>,
+    ) -> ControlFlow<EarlyExit<R, Replacement<'ptr, 'ptr, Ptr>, C>, C>,
+) -> ControlFlow<R, (C, Ptr)> {
+    let mut this = UnwindGuard(ManuallyDrop::new(this));
+
+    let ctrl = {
+        let erased_borrow = unsafe { Reborrow::extend(Ptr::reborrow(&mut this.0)) };
+
+        block(erased_borrow)
+    };
+
+    let this = this.disarm();
+
+    match ctrl {
+        ControlFlow::Break(EarlyExit::Return(out)) => ControlFlow::Break(out),
+        // SAFETY: The replacement was derived from the extended borrow, which is valid for `'ptr`
+        // now that `this` will never be used again.
+        ControlFlow::Break(EarlyExit::Replace((ptr, restore), out)) => {
+            ControlFlow::Continue((out, restore(unsafe { Reborrow::extend(ptr) })))
+        }
         ControlFlow::Continue(out) => ControlFlow::Continue((out, ManuallyDrop::into_inner(this))),
     }
 }
@@ -51,6 +51,13 @@ where
     }
 }
 
+unsafe impl<'ptr> Reborrow<'ptr> for () {
+    type BorrowWithLifetime = WithLt![()];
+
+    fn reborrow<'b>(_: &'b mut ()) -> BorrowedAs<'b, Self::BorrowWithLifetime> {}
+    unsafe fn extend<'b>(_: ()) -> BorrowedAs<'b, Self::BorrowWithLifetime> {}
+}
+
 unsafe impl<'ptr, P1, P2> Reborrow<'ptr> for (P1, P2)
 where
     P1: Reborrow<'ptr>,
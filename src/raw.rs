@@ -0,0 +1,238 @@
+#![allow(clippy::needless_lifetimes)]
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr::NonNull,
+};
+
+use crate::{traits::Reborrow, WithLt};
+
+/// A mutable reference stored as a [`NonNull`] pointer, for data structures that can't hold a
+/// `&'a mut T` directly, such as intrusive lists and FFI wrappers.
+///
+/// Like `&'a mut T`, it implements [`Reborrow`], so it can be passed to
+/// [`maybe_borrow!`](crate::maybe_borrow), and the target is accessed through [`Deref`] and
+/// [`DerefMut`].
+///
+/// ```
+/// use std::ptr::NonNull;
+/// use maybe_borrow::{prelude::*, RawMut};
+///
+/// struct Node {
+///     value: i32,
+///     next: Option<NonNull<Node>>,
+/// }
+///
+/// /// Returns the first node with the given value, or the last node in the list.
+/// ///
+/// /// ## Safety
+/// /// Every `next` pointer must be valid and not aliased for `'a`.
+/// unsafe fn find_or_last<'a>(mut node: RawMut<'a, Node>, value: i32) -> &'a mut Node {
+///     loop {
+///         let advanced = maybe_borrow!(for<'x> |node| -> &'x mut Node {
+///             if node.value == value {
+///                 return_borrowed!(RawMut::into_mut(node));
+///             }
+///
+///             if let Some(next) = node.next {
+///                 // SAFETY: `next` is valid for `'a`, which outlives the reborrow of `node`.
+///                 continue_with!(node, unsafe { RawMut::from_non_null(next) }, true);
+///             }
+///
+///             false
+///         });
+///
+///         if !advanced {
+///             return RawMut::into_mut(node);
+///         }
+///     }
+/// }
+///
+/// let mut last = Node { value: 2, next: None };
+/// let mut first = Node { value: 1, next: Some(NonNull::from(&mut last)) };
+/// unsafe { find_or_last(RawMut::new(&mut first), 3) }.value += 10;
+/// assert_eq!(last.value, 12);
+/// ```
+pub struct RawMut<'a, T: ?Sized> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: `RawMut` has the same semantics as `&'a mut T`.
+unsafe impl<T: ?Sized + Send> Send for RawMut<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RawMut<'_, T> {}
+
+impl<'a, T: ?Sized> RawMut<'a, T> {
+    /// Creates a pointer from a mutable reference.
+    pub fn new(target: &'a mut T) -> Self {
+        Self {
+            ptr: NonNull::from(target),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a pointer from a raw pointer.
+    ///
+    /// ## Safety
+    /// `ptr` must be valid for converting to a `&'a mut T`: it must point to a valid `T` that
+    /// isn't accessed through any other pointer for `'a`.
+    pub unsafe fn from_non_null(ptr: NonNull<T>) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the raw pointer.
+    ///
+    /// This is an associated function so that it doesn't shadow methods of the target.
+    pub fn as_non_null(this: &Self) -> NonNull<T> {
+        this.ptr
+    }
+
+    /// Converts the pointer into a mutable reference to the target.
+    pub fn into_mut(this: Self) -> &'a mut T {
+        let mut ptr = this.ptr;
+        // SAFETY: The pointer is valid for `'a`, and it's consumed.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// Converts the pointer into a shared pointer to the target.
+    pub fn into_ref(this: Self) -> RawRef<'a, T> {
+        RawRef {
+            ptr: this.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a mut T> for RawMut<'a, T> {
+    fn from(target: &'a mut T) -> Self {
+        Self::new(target)
+    }
+}
+
+impl<T: ?Sized> Deref for RawMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The pointer is valid for `'a`, and `self` is borrowed for the returned lifetime.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for RawMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The pointer is valid for `'a`, and `self` is borrowed for the returned lifetime.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+unsafe impl<'ptr, T: ?Sized> Reborrow<'ptr> for RawMut<'ptr, T> {
+    type BorrowWithLifetime = WithLt!['b -> RawMut<'b, T>];
+
+    fn reborrow<'b>(this: &'b mut Self) -> RawMut<'b, T> {
+        RawMut {
+            ptr: this.ptr,
+            _marker: PhantomData,
+        }
+    }
+    unsafe fn extend<'b>(this: Self) -> RawMut<'b, T> {
+        RawMut {
+            ptr: this.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A shared reference stored as a [`NonNull`] pointer.
+///
+/// This is the shared counterpart of [`RawMut`], with the same semantics as `&'a T`.
+pub struct RawRef<'a, T: ?Sized> {
+    ptr: NonNull<T>,
+    _marker: PhantomData<&'a T>,
+}
+
+// SAFETY: `RawRef` has the same semantics as `&'a T`.
+unsafe impl<T: ?Sized + Sync> Send for RawRef<'_, T> {}
+unsafe impl<T: ?Sized + Sync> Sync for RawRef<'_, T> {}
+
+impl<T: ?Sized> Clone for RawRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized> Copy for RawRef<'_, T> {}
+
+impl<'a, T: ?Sized> RawRef<'a, T> {
+    /// Creates a pointer from a shared reference.
+    pub fn new(target: &'a T) -> Self {
+        Self {
+            ptr: NonNull::from(target),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates a pointer from a raw pointer.
+    ///
+    /// ## Safety
+    /// `ptr` must be valid for converting to a `&'a T`: it must point to a valid `T` that isn't
+    /// mutated for `'a`, other than through an `UnsafeCell`.
+    pub unsafe fn from_non_null(ptr: NonNull<T>) -> Self {
+        Self {
+            ptr,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the raw pointer.
+    ///
+    /// This is an associated function so that it doesn't shadow methods of the target.
+    pub fn as_non_null(this: &Self) -> NonNull<T> {
+        this.ptr
+    }
+
+    /// Converts the pointer into a shared reference to the target.
+    pub fn get(this: Self) -> &'a T {
+        // SAFETY: The pointer is valid for `'a`.
+        unsafe { this.ptr.as_ref() }
+    }
+}
+
+impl<'a, T: ?Sized> From<&'a T> for RawRef<'a, T> {
+    fn from(target: &'a T) -> Self {
+        Self::new(target)
+    }
+}
+
+impl<'a, T: ?Sized> From<RawMut<'a, T>> for RawRef<'a, T> {
+    fn from(ptr: RawMut<'a, T>) -> Self {
+        RawMut::into_ref(ptr)
+    }
+}
+
+impl<T: ?Sized> Deref for RawRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        RawRef::get(*self)
+    }
+}
+
+unsafe impl<'ptr, T: ?Sized> Reborrow<'ptr> for RawRef<'ptr, T> {
+    type BorrowWithLifetime = WithLt!['b -> RawRef<'b, T>];
+
+    fn reborrow<'b>(this: &'b mut Self) -> RawRef<'b, T> {
+        RawRef {
+            ptr: this.ptr,
+            _marker: PhantomData,
+        }
+    }
+    unsafe fn extend<'b>(this: Self) -> RawRef<'b, T> {
+        RawRef {
+            ptr: this.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{maybe_borrow_impl::BorrowedAs, with_lt::WithLt};
 
+/// Maps a lifetime `'b` to the pointer type produced by reborrowing for `'b`.
+///
+/// This is implemented automatically for the output of the `WithLt!` macro.
 pub trait BorrowWithLifetime<'b> {
     type Pointer: Reborrow<'b, BorrowWithLifetime = Self>;
 }
@@ -12,11 +15,97 @@ where
     type Pointer = W::Actual<'b>;
 }
 
+/// A pointer that can be passed to [`maybe_borrow!`](crate::maybe_borrow) or
+/// [`try_maybe_borrow!`](crate::try_maybe_borrow).
+///
 /// ## Safety
 /// The pointer must be safe to use again after lifetime `'b` ends.
+///
+/// The original pointer is forgotten rather than dropped when the block returns borrowed data,
+/// and is dropped exactly once if the block panics.
+///
+/// ## Implementing
+/// `BorrowWithLifetime` is usually written with [`WithLt!`](macro@crate::WithLt) as the pointer
+/// type with `'ptr` replaced (`WithLt!['b -> &'b mut T]` for `&'ptr mut T`), and structs of
+/// pointers can use [`impl_reborrow!`](crate::impl_reborrow) instead.
+///
+/// The reborrowed type isn't a generic associated type (`type Reborrowed<'b> where Self: 'b`)
+/// because the blocks reborrow pointers for lifetimes they know nothing about. For a concrete
+/// pointer type, the `Self: 'b` bound would then have to hold for every lifetime, requiring the
+/// pointer to be `'static`. A type-level function from `WithLt!` doesn't depend on `Self`, so it has
+/// no such bound.
 #[allow(clippy::needless_lifetimes)]
 pub unsafe trait Reborrow<'ptr> {
     type BorrowWithLifetime: ?Sized + 'ptr + for<'b> BorrowWithLifetime<'b>;
+
+    /// Reborrows the pointer for the lifetime `'b`.
     fn reborrow<'b>(this: &'b mut Self) -> BorrowedAs<'b, Self::BorrowWithLifetime>;
+
+    /// Converts the pointer to the same pointer with an arbitrary lifetime.
+    ///
+    /// ## Safety
+    /// The caller must ensure the returned pointer is not used after `'ptr` ends.
     unsafe fn extend<'b>(this: Self) -> BorrowedAs<'b, Self::BorrowWithLifetime>;
 }
+
+/// A bundle of pointers that can be reborrowed by converting it to and from another pointer,
+/// usually a tuple of its fields.
+///
+/// This is safe to implement, and [`impl_reborrow!`](crate::impl_reborrow) can then implement
+/// [`Reborrow`] for the bundle without any unsafe code.
+///
+/// ```
+/// use maybe_borrow::{prelude::*, ReborrowFields};
+///
+/// struct Pair<'a, T>(&'a mut Vec<T>, &'a mut T);
+///
+/// impl<'ptr, T> ReborrowFields<'ptr> for Pair<'ptr, T> {
+///     type Fields = (&'ptr mut Vec<T>, &'ptr mut T);
+///
+///     fn into_fields(self) -> Self::Fields {
+///         (self.0, self.1)
+///     }
+///
+///     fn from_fields((items, fallback): Self::Fields) -> Self {
+///         Pair(items, fallback)
+///     }
+///
+///     fn reborrow_fields(this: &mut Self) -> (&mut Vec<T>, &mut T) {
+///         (&mut *this.0, &mut *this.1)
+///     }
+/// }
+///
+/// maybe_borrow::impl_reborrow! {
+///     impl<T> Reborrow for Pair<'_, T> via ReborrowFields
+/// }
+///
+/// /// Returns the last item, or the fallback if there are no items.
+/// fn last_or_fallback<T>(mut pair: Pair<T>) -> &mut T {
+///     maybe_borrow!(for<'x> |pair| -> &'x mut T {
+///         if let Some(last) = pair.0.last_mut() {
+///             return_borrowed!(last);
+///         }
+///     });
+///
+///     pair.1
+/// }
+///
+/// let (mut items, mut fallback) = (vec![], 1);
+/// *last_or_fallback(Pair(&mut items, &mut fallback)) += 1;
+/// assert_eq!(fallback, 2);
+/// ```
+pub trait ReborrowFields<'ptr>: Sized {
+    /// The pointer the bundle converts to.
+    type Fields: Reborrow<'ptr>;
+
+    /// Converts the bundle into its fields.
+    fn into_fields(self) -> Self::Fields;
+
+    /// Converts the fields back into the bundle.
+    fn from_fields(fields: Self::Fields) -> Self;
+
+    /// Reborrows the bundle's fields for the lifetime `'b`.
+    fn reborrow_fields<'b>(
+        this: &'b mut Self,
+    ) -> BorrowedAs<'b, <Self::Fields as Reborrow<'ptr>>::BorrowWithLifetime>;
+}
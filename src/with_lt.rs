@@ -16,11 +16,14 @@ where
     type Value = T;
 }
 
+/// A type with a lifetime `'a` substituted in, used to bound [`WithLt`] for a single lifetime.
 pub trait WithLtHrtb<'a> {
     type ActualLt;
 }
 
+/// A type-level function from a lifetime to a type, created with [`WithLt!`](macro@crate::WithLt).
 pub trait WithLt: for<'a> WithLtHrtb<'a, ActualLt = Self::Actual<'a>> {
+    /// The type with `'a` substituted in.
     type Actual<'a>;
 }
 
@@ -37,20 +40,76 @@ impl<L: ?Sized + for<'a> Lt<'a>> WithLt for _With<L> {
 #[doc(hidden)]
 pub type Actual<'a, W> = <W as WithLtHrtb<'a>>::ActualLt;
 
+#[doc(hidden)]
+pub trait Lt2<'a, 'b> {
+    type Value;
+}
+
+/// A type with lifetimes `'a` and `'b` substituted in, used to bound [`WithLt2`] for a pair of
+/// lifetimes.
+pub trait WithLt2Hrtb<'a, 'b> {
+    type ActualLt;
+}
+
+/// A type-level function from two lifetimes to a type, created with [`WithLt!`](macro@crate::WithLt).
+pub trait WithLt2: for<'a, 'b> WithLt2Hrtb<'a, 'b, ActualLt = Self::Actual<'a, 'b>> {
+    /// The type with `'a` and `'b` substituted in.
+    type Actual<'a, 'b>;
+}
+
+#[doc(hidden)]
+impl<'a, 'b, L: ?Sized + Lt2<'a, 'b>> WithLt2Hrtb<'a, 'b> for _With<L> {
+    type ActualLt = L::Value;
+}
+
+#[doc(hidden)]
+impl<L: ?Sized + for<'a, 'b> Lt2<'a, 'b>> WithLt2 for _With<L> {
+    type Actual<'a, 'b> = <L as Lt2<'a, 'b>>::Value;
+}
+
+/// The type produced by a two-lifetime [`WithLt!`](macro@crate::WithLt) for `'a` and `'b`.
+///
+/// Unlike [`WithLt2::Actual`], this only needs [`WithLt2Hrtb`] for the given lifetimes, so it can
+/// be used with bounds like `W: WithLt2Hrtb<'a, 'b>`.
+pub type Apply<'a, 'b, W> = <W as WithLt2Hrtb<'a, 'b>>::ActualLt;
+
 mod macros {
-    #[doc(hidden)]
+    /// Creates a type-level function from one or two lifetimes to a type.
+    ///
+    /// - `WithLt!['a -> Ty<'a>]` implements [`WithLt`](trait@crate::WithLt), with
+    ///   `<W as WithLt>::Actual<'x>` being `Ty<'x>`.
+    /// - `WithLt!['a, 'b -> Ty<'a, 'b>]` implements [`WithLt2`](crate::WithLt2), with
+    ///   [`Apply<'x, 'y, W>`](crate::Apply) being `Ty<'x, 'y>`.
+    /// - `WithLt![Ty]` implements `WithLt` for a type that doesn't use the lifetime.
+    ///
+    /// The single-lifetime form is what [`Reborrow::BorrowWithLifetime`](crate::Reborrow) expects.
+    ///
+    /// ```
+    /// use maybe_borrow::{Apply, WithLt, WithLt2};
+    ///
+    /// type Pair = WithLt!['a, 'b -> (&'a str, &'b mut [u8])];
+    ///
+    /// fn first<'a, 'b, W: WithLt2>(pairs: Vec<W::Actual<'a, 'b>>) -> Option<Apply<'a, 'b, W>> {
+    ///     pairs.into_iter().next()
+    /// }
+    ///
+    /// let mut bytes = [1, 2];
+    /// let (name, bytes) = first::<Pair>(vec![("a", &mut bytes[..])]).unwrap();
+    /// bytes[0] = 3;
+    /// assert_eq!(name, "a");
+    /// ```
     #[macro_export]
     macro_rules! WithLt {
         ($lt:lifetime -> $Ty:ty) => {
             $crate::_m::_With<dyn for<$lt> $crate::_m::Lt<$lt, Value = $Ty>>
         };
+        ($lt0:lifetime, $lt1:lifetime -> $Ty:ty) => {
+            $crate::_m::_With<dyn for<$lt0, $lt1> $crate::_m::Lt2<$lt0, $lt1, Value = $Ty>>
+        };
         ($Ty:ty) => {
             $crate::_m::_With<
                 fn(&()) -> $crate::_m::PhantomData<(&(), $Ty)>
             >
         };
     }
-    pub use WithLt;
 }
-
-pub use macros::WithLt;
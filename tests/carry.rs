@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use maybe_borrow::prelude::*;
+
+/// Returns the value for `key`, inserting `key` only if it's alphanumeric. If there's no value
+/// and `key` isn't inserted, it's handed back to the caller.
+fn get_or_insert_ident(
+    mut map: &mut HashMap<String, usize>,
+    mut key: String,
+) -> Result<&mut usize, String> {
+    maybe_borrow!(for<'x> |map, move key| -> Result<&'x mut usize, String> {
+        if key.chars().all(char::is_alphanumeric) {
+            let len = key.len();
+            return_borrowed!(Ok(map.entry(key).or_insert(len)));
+        }
+        if let Some(value) = map.get_mut(&key) {
+            return_borrowed!(Ok(value));
+        }
+    });
+
+    // `key` wasn't consumed by the block, so it's available again here.
+    Err(key)
+}
+
+#[test]
+fn consumed_only_on_return_path() {
+    let mut map = HashMap::new();
+
+    *get_or_insert_ident(&mut map, "abc".into()).unwrap() += 10;
+    assert_eq!(map["abc"], 13);
+    assert_eq!(*get_or_insert_ident(&mut map, "abc".into()).unwrap(), 13);
+
+    assert_eq!(
+        get_or_insert_ident(&mut map, "a b".into()).unwrap_err(),
+        "a b"
+    );
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn multiple_carried_values() {
+    fn lookup<'a>(
+        mut map: &'a mut HashMap<String, Vec<i32>>,
+        mut key: String,
+        mut values: Vec<i32>,
+    ) -> Result<&'a mut Vec<i32>, (String, Vec<i32>)> {
+        maybe_borrow!(for<'x> |map, move key, move values| -> Result<&'x mut Vec<i32>, (String, Vec<i32>)> {
+            if let Some(existing) = map.get_mut(&key) {
+                existing.append(&mut values);
+                return_borrowed!(Ok(existing));
+            }
+        });
+
+        Err((key, values))
+    }
+
+    let mut map = HashMap::from([("a".to_owned(), vec![1])]);
+    assert_eq!(
+        lookup(&mut map, "a".into(), vec![2, 3]).unwrap(),
+        &[1, 2, 3]
+    );
+    assert_eq!(
+        lookup(&mut map, "b".into(), vec![4]).unwrap_err(),
+        ("b".to_owned(), vec![4])
+    );
+}
+
+#[test]
+fn carried_alongside_continue_value() {
+    let mut map = HashMap::from([("a".to_owned(), 1)]);
+    let mut key = String::from("b");
+    let mut map_ref = &mut map;
+
+    let len = maybe_borrow!(for<'x> |map_ref, move key| -> () {
+        if let Some(value) = map_ref.get_mut(&key) {
+            *value += 1;
+        }
+        key.push('c');
+        key.len()
+    });
+
+    assert_eq!(len, 2);
+    assert_eq!(key, "bc");
+    map_ref.insert(key, len);
+    assert_eq!(map["bc"], 2);
+}
+
+#[test]
+fn carried_through_continue_with() {
+    struct Node {
+        name: String,
+        children: Vec<Node>,
+    }
+
+    /// Descends through the first child of each node until it finds one named `name`, returning
+    /// the deepest node reached and `name` if it wasn't found.
+    fn find_or_last(mut node: &mut Node, mut name: String) -> (&mut Node, Option<String>) {
+        loop {
+            let advanced = maybe_borrow!(for<'x> |node, move name| -> (&'x mut Node, Option<String>) {
+                if node.name == name {
+                    return_borrowed!((node, None));
+                }
+                if let Some(first) = node.children.first_mut() {
+                    continue_with!(node, first, true);
+                }
+
+                false
+            });
+
+            if !advanced {
+                return (node, Some(name));
+            }
+        }
+    }
+
+    let mut tree = Node {
+        name: "a".into(),
+        children: vec![Node {
+            name: "b".into(),
+            children: vec![],
+        }],
+    };
+
+    let (found, name) = find_or_last(&mut tree, "b".into());
+    assert_eq!(found.name, "b");
+    assert_eq!(name, None);
+
+    let (last, name) = find_or_last(&mut tree, "c".into());
+    last.children.push(Node {
+        name: name.unwrap(),
+        children: vec![],
+    });
+    assert_eq!(tree.children[0].children[0].name, "c");
+}
+
+#[test]
+fn carried_in_try_maybe_borrow() {
+    fn parse_or_get(
+        mut map: &mut HashMap<String, i32>,
+        mut key: String,
+    ) -> Option<Result<&mut i32, String>> {
+        try_maybe_borrow!(for<'x> |map, move key| -> Option<Result<&'x mut i32, String>> {
+            let digit = key.chars().next()?;
+            if let Some(digit) = digit.to_digit(10) {
+                return_borrowed!(Some(Ok(map.entry(key).or_insert(digit as i32))));
+            }
+            if let Some(value) = map.get_mut(&key) {
+                return_borrowed!(Some(Ok(value)));
+            }
+        });
+
+        Some(Err(key))
+    }
+
+    let mut map = HashMap::new();
+    assert!(parse_or_get(&mut map, String::new()).is_none());
+    assert_eq!(*parse_or_get(&mut map, "7a".into()).unwrap().unwrap(), 7);
+    assert_eq!(
+        parse_or_get(&mut map, "x".into()).unwrap().unwrap_err(),
+        "x"
+    );
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn carried_with_multiple_lifetimes() {
+    fn get_pair<'a, 'b>(
+        mut a: &'a mut HashMap<String, i32>,
+        mut b: &'b mut HashMap<String, i32>,
+        mut key: String,
+    ) -> Result<(&'a mut i32, &'b mut i32), String> {
+        maybe_borrow!(for<'x, 'y> |a, b, move key| -> Result<(&'x mut i32, &'y mut i32), String> {
+            if let (Some(x), Some(y)) = (a.get_mut(&key), b.get_mut(&key)) {
+                return_borrowed!(Ok((x, y)));
+            }
+        });
+
+        Err(key)
+    }
+
+    let mut a = HashMap::from([("k".to_owned(), 1)]);
+    let mut b = HashMap::from([("k".to_owned(), 2)]);
+    let (x, y) = get_pair(&mut a, &mut b, "k".into()).unwrap();
+    *x += *y;
+    assert_eq!(a["k"], 3);
+    assert_eq!(get_pair(&mut a, &mut b, "j".into()).unwrap_err(), "j");
+}
@@ -0,0 +1,139 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::{future, task::noop_waker_ref};
+
+use maybe_borrow::prelude::*;
+
+#[test]
+fn unsized_coercion() {
+    /// Returns the first negative value, or the whole slice if there isn't one.
+    fn first_negative(src: &mut Vec<i32>) -> Result<&mut i32, &mut [i32]> {
+        maybe_borrow!(
+            for<'x> |src: &mut [i32]| -> Result<&'x mut i32, &'x mut [i32]> {
+                if let Some(x) = src.iter_mut().find(|x| **x < 0) {
+                    return_borrowed!(Ok(x));
+                }
+            }
+        );
+
+        // `src` has been rebound as the coerced slice.
+        Err(src)
+    }
+
+    let mut values = vec![1, -2, 3];
+    *first_negative(&mut values).unwrap() = 2;
+    assert_eq!(first_negative(&mut values).unwrap_err(), [1, 2, 3]);
+}
+
+#[test]
+fn trait_object_coercion() {
+    fn downcast<T: Any>(src: &mut T) -> Result<&mut i32, &mut dyn Any> {
+        maybe_borrow!(
+            for<'x> |src: &mut dyn Any| -> Result<&'x mut i32, &'x mut dyn Any> {
+                if let Some(x) = src.downcast_mut::<i32>() {
+                    return_borrowed!(Ok(x));
+                }
+            }
+        );
+
+        Err(src)
+    }
+
+    let mut x = 1;
+    *downcast(&mut x).unwrap() += 1;
+    assert_eq!(x, 2);
+
+    let mut map = HashMap::<i32, i32>::new();
+    assert!(downcast(&mut map).unwrap_err().is::<HashMap<i32, i32>>());
+}
+
+#[test]
+fn initializer() {
+    /// Polls `fut`, returning a reference to its output if it completes with a value.
+    fn poll_boxed<'a, F: Future<Output = Option<i32>>>(
+        fut: &'a mut Pin<Box<F>>,
+        mut slot: &'a mut Option<i32>,
+        cx: &mut Context,
+    ) -> Poll<Option<&'a mut i32>> {
+        maybe_borrow!(for<'x> |fut = fut.as_mut(), slot| -> Poll<Option<&'x mut i32>> {
+            if let Some(x) = ready!(fut.as_mut().poll(cx)) {
+                return_borrowed!(Poll::Ready(Some(slot.insert(x))));
+            }
+        });
+
+        // Both pointers are usable after the block, including the `Pin<&mut F>` binding.
+        let _fut: Pin<&mut F> = fut;
+        *slot = None;
+        Poll::Ready(None)
+    }
+
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut slot = None;
+
+    let mut fut = Box::pin(future::ready(Some(1)));
+    let Poll::Ready(Some(out)) = poll_boxed(&mut fut, &mut slot, cx) else {
+        panic!("expected borrowed value");
+    };
+    *out += 1;
+    assert_eq!(slot, Some(2));
+
+    let mut fut = Box::pin(future::ready(None));
+    assert!(matches!(
+        poll_boxed(&mut fut, &mut slot, cx),
+        Poll::Ready(None)
+    ));
+    assert_eq!(slot, None);
+
+    let mut fut = Box::pin(future::pending());
+    assert!(poll_boxed(&mut fut, &mut slot, cx).is_pending());
+}
+
+#[test]
+fn annotated_initializer_with_carry() {
+    fn get_or_insert<'a>(map: &'a mut HashMap<String, Vec<i32>>, mut key: String) -> &'a mut [i32] {
+        maybe_borrow!(for<'x> |
+            entry: &mut Vec<i32> = map.entry(key.clone()).or_default(),
+            move key,
+        | -> &'x mut [i32] {
+            if !entry.is_empty() {
+                return_borrowed!(entry);
+            }
+        });
+
+        entry.push(key.len() as i32);
+        entry
+    }
+
+    let mut map = HashMap::new();
+    assert_eq!(get_or_insert(&mut map, "abc".into()), [3]);
+    assert_eq!(get_or_insert(&mut map, "abc".into()), [3]);
+}
+
+#[test]
+fn initializer_in_try_maybe_borrow() {
+    fn last_positive(values: &mut Vec<i32>) -> Option<&mut i32> {
+        try_maybe_borrow!(for<'x> |values: &mut [i32] = values| -> Option<&'x mut i32> {
+            let last = values.last_mut()?;
+            if *last > 0 {
+                return_borrowed!(Some(last));
+            }
+        });
+
+        let (last, _) = values.split_last_mut()?;
+        *last = -*last;
+        None
+    }
+
+    assert_eq!(last_positive(&mut vec![]), None);
+    assert_eq!(last_positive(&mut vec![1, 2]), Some(&mut 2));
+
+    let mut values = vec![-1];
+    assert_eq!(last_positive(&mut values), None);
+    assert_eq!(values, [1]);
+}
@@ -0,0 +1,122 @@
+use std::{collections::HashMap, num::ParseIntError, pin::Pin};
+
+use maybe_borrow::compat::polonius::*;
+
+/// Returns the value for `key`, or the number of entries if it isn't in the map.
+fn get_or_len<'a>(mut map: &'a mut HashMap<u32, String>, key: u32) -> Result<&'a str, usize> {
+    let len = polonius!(|map| -> Result<&'polonius str, usize> {
+        if let Some(value) = map.get(&key) {
+            polonius_return!(Ok(value));
+        }
+        exit_polonius!(map.len())
+    });
+
+    Err(len)
+}
+
+#[test]
+fn exit() {
+    let mut map = HashMap::from([(1, "one".to_string())]);
+    assert_eq!(get_or_len(&mut map, 1), Ok("one"));
+    assert_eq!(get_or_len(&mut map, 2), Err(1));
+}
+
+/// Returns the element at the index parsed from `index`, or pushes one if it's out of bounds.
+fn parsed_index<'a>(
+    mut values: &'a mut Vec<i32>,
+    index: &str,
+) -> Result<&'a mut i32, ParseIntError> {
+    polonius!(|values| -> Result<&'polonius mut i32, ParseIntError> {
+        let index: usize = polonius_try!(index.parse());
+        if let Some(value) = values.get_mut(index) {
+            polonius_return!(Ok(value));
+        }
+    });
+
+    values.push(0);
+    Ok(values.last_mut().unwrap())
+}
+
+#[test]
+fn try_() {
+    let mut values = vec![1];
+    *parsed_index(&mut values, "0").unwrap() += 1;
+    *parsed_index(&mut values, "3").unwrap() += 1;
+    assert!(parsed_index(&mut values, "x").is_err());
+    assert_eq!(values, [2, 1]);
+}
+
+/// Returns the first negative value in either vector, or pushes one onto `b`.
+fn first_negative<'a>(mut a: &'a mut Vec<i32>, mut b: &'a mut Vec<i32>) -> &'a mut i32 {
+    polonius!(|a, b| -> &'polonius mut i32 {
+        if let Some(x) = a.iter_mut().chain(b.iter_mut()).find(|x| **x < 0) {
+            polonius_return!(x);
+        }
+    });
+
+    a.clear();
+    b.push(-1);
+    b.last_mut().unwrap()
+}
+
+#[test]
+fn multiple_pointers() {
+    let (mut a, mut b) = (vec![1], vec![2, -3]);
+    *first_negative(&mut a, &mut b) -= 1;
+    assert_eq!((&a[..], &b[..]), (&[1][..], &[2, -4][..]));
+
+    let mut b = vec![];
+    *first_negative(&mut a, &mut b) -= 1;
+    assert_eq!((&a[..], &b[..]), (&[][..], &[-2][..]));
+}
+
+/// Returns the first value, or pushes one.
+fn pinned(mut values: Pin<&mut Vec<i32>>) -> Pin<&mut i32> {
+    polonius!(|values| -> Pin<&'polonius mut i32> {
+        if !values.is_empty() {
+            polonius_return!(Pin::new(&mut values.get_mut()[0]));
+        }
+    });
+
+    let values = values.get_mut();
+    values.push(0);
+    Pin::new(values.last_mut().unwrap())
+}
+
+#[test]
+fn pin() {
+    let mut values = vec![];
+    *pinned(Pin::new(&mut values)) += 1;
+    *pinned(Pin::new(&mut values)) += 1;
+    assert_eq!(values, [2]);
+}
+
+/// Returns the first value greater than `min`, skipping `skip`, or the number of values checked.
+fn first_greater(mut values: &mut Vec<i32>, min: i32, skip: i32) -> Result<&mut i32, usize> {
+    let mut index = 0;
+    let checked = polonius_loop!(|values| -> Result<&'polonius mut i32, usize> {
+        let i = index;
+        index += 1;
+        if i >= values.len() {
+            polonius_break!(i);
+        }
+        if values[i] == skip {
+            polonius_continue!();
+        }
+        if values[i] > min {
+            polonius_return!(Ok(&mut values[i]));
+        }
+    });
+
+    values.clear();
+    Err(checked)
+}
+
+#[test]
+fn loop_() {
+    let mut values = vec![1, 5, 7];
+    *first_greater(&mut values, 3, 5).unwrap() += 1;
+    assert_eq!(values, [1, 5, 8]);
+    assert_eq!(first_greater(&mut values, 10, 5), Err(3));
+    assert_eq!(values, []);
+}
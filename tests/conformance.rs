@@ -0,0 +1,130 @@
+use std::{
+    cell::Cell,
+    marker::PhantomPinned,
+    mem::ManuallyDrop,
+    pin::{pin, Pin},
+    ptr,
+};
+
+use maybe_borrow::{prelude::*, Reborrow};
+
+#[derive(Default)]
+struct Drops {
+    originals: Cell<usize>,
+    reborrows: Cell<usize>,
+}
+
+/// A pointer with drop glue that records how many times it was dropped.
+struct Tracked<'a> {
+    value: &'a mut i32,
+    drops: &'a Drops,
+    original: bool,
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        let count = match self.original {
+            true => &self.drops.originals,
+            false => &self.drops.reborrows,
+        };
+        count.set(count.get() + 1);
+    }
+}
+
+impl std::ops::Deref for Tracked<'_> {
+    type Target = i32;
+
+    fn deref(&self) -> &i32 {
+        self.value
+    }
+}
+
+unsafe impl<'ptr> Reborrow<'ptr> for Tracked<'ptr> {
+    type BorrowWithLifetime = maybe_borrow::WithLt!['b -> Tracked<'b>];
+
+    fn reborrow<'b>(this: &'b mut Self) -> Tracked<'b> {
+        Tracked {
+            value: this.value,
+            drops: this.drops,
+            original: false,
+        }
+    }
+
+    unsafe fn extend<'b>(this: Self) -> Tracked<'b> {
+        let this = ManuallyDrop::new(this);
+        // Moved out rather than reborrowed, so it keeps its permission to write.
+        let value = unsafe { ptr::read(&this.value) };
+        Tracked {
+            value: unsafe { &mut *(value as *mut i32) },
+            drops: unsafe { &*(this.drops as *const Drops) },
+            original: this.original,
+        }
+    }
+}
+
+struct Pair<'a>(&'a mut Vec<i32>, Pin<&'a mut i32>);
+
+impl<'ptr> maybe_borrow::ReborrowFields<'ptr> for Pair<'ptr> {
+    type Fields = (&'ptr mut Vec<i32>, Pin<&'ptr mut i32>);
+
+    fn into_fields(self) -> Self::Fields {
+        (self.0, self.1)
+    }
+
+    fn from_fields((items, value): Self::Fields) -> Self {
+        Pair(items, value)
+    }
+
+    fn reborrow_fields(this: &mut Self) -> (&mut Vec<i32>, Pin<&mut i32>) {
+        (&mut *this.0, this.1.as_mut())
+    }
+}
+
+maybe_borrow::impl_reborrow! {
+    impl Reborrow for Pair<'_> via ReborrowFields
+}
+
+maybe_borrow::reborrow_conformance_tests!(tracked, for<'a> Tracked<'a>: Deref, |check| {
+    let (mut value, drops) = (1, Drops::default());
+    let expected = check(Tracked {
+        value: &mut value,
+        drops: &drops,
+        original: true,
+    });
+    assert_eq!(drops.originals.get(), expected);
+    assert!(drops.reborrows.get() > 0);
+    assert_eq!(value, 1);
+});
+
+maybe_borrow::reborrow_conformance_tests!(mut_ref, for<'a> &'a mut i32: Deref, |check| {
+    check(&mut 1);
+});
+
+maybe_borrow::reborrow_conformance_tests!(shared_ref, for<'a> &'a str: Deref, |check| {
+    check("abc");
+});
+
+maybe_borrow::reborrow_conformance_tests!(pinned, for<'a> Pin<&'a mut i32>: Deref, |check| {
+    check(Pin::new(&mut 1));
+});
+
+/// A target that can't be moved once it's pinned.
+struct Unmovable {
+    value: i32,
+    _pinned: PhantomPinned,
+}
+
+maybe_borrow::reborrow_conformance_tests!(unmovable, for<'a> Pin<&'a mut Unmovable>: Deref + ?Unpin, |check| {
+    let mut unmovable = pin!(Unmovable {
+        value: 1,
+        _pinned: PhantomPinned,
+    });
+    check(unmovable.as_mut());
+    assert_eq!(unmovable.value, 1);
+});
+
+maybe_borrow::reborrow_conformance_tests!(fields, for<'a> Pair<'a>, |check| {
+    let (mut items, mut value) = (vec![1], 2);
+    check(Pair(&mut items, Pin::new(&mut value)));
+    assert_eq!((items, value), (vec![1], 2));
+});
@@ -0,0 +1,220 @@
+use std::cmp::Ordering;
+
+use maybe_borrow::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+impl Node {
+    fn from_values(values: impl IntoIterator<Item = i32>) -> Option<Box<Node>> {
+        let mut values: Vec<_> = values.into_iter().collect();
+        values.reverse();
+        values
+            .into_iter()
+            .fold(None, |next, value| Some(Box::new(Node { value, next })))
+    }
+
+    fn values(&self) -> Vec<i32> {
+        std::iter::successors(Some(self), |node| node.next.as_deref())
+            .map(|node| node.value)
+            .collect()
+    }
+}
+
+/// Returns the first node with the given value, or the last node in the list.
+fn find_or_last(mut node: &mut Node, value: i32) -> &mut Node {
+    loop {
+        let advanced = maybe_borrow!(for<'x> |node| -> &'x mut Node {
+            if node.value == value {
+                return_borrowed!(node);
+            }
+
+            if let Some(next) = &mut node.next {
+                continue_with!(node, next, true);
+            }
+
+            false
+        });
+
+        if !advanced {
+            return node;
+        }
+    }
+}
+
+#[test]
+fn linked_list_find() {
+    let mut list = Node::from_values([1, 2, 3, 4]).unwrap();
+
+    find_or_last(&mut list, 2).value += 10;
+    assert_eq!(list.values(), [1, 12, 3, 4]);
+
+    find_or_last(&mut list, 5).value += 10;
+    assert_eq!(list.values(), [1, 12, 3, 14]);
+}
+
+#[test]
+fn linked_list_push_back() {
+    fn push_back(mut node: &mut Node, value: i32) {
+        loop {
+            maybe_borrow!(for<'x> |node| -> () {
+                if let Some(next) = &mut node.next {
+                    continue_with!(node, next);
+                }
+            });
+
+            // `node` has no successor, so it's the last node in the list.
+            if node.next.is_none() {
+                node.next = Node::from_values([value]);
+                return;
+            }
+        }
+    }
+
+    let mut list = Node::from_values([1]).unwrap();
+    push_back(&mut list, 2);
+    push_back(&mut list, 3);
+    assert_eq!(list.values(), [1, 2, 3]);
+}
+
+#[derive(Default)]
+struct Tree {
+    root: Option<Box<TreeNode>>,
+}
+
+struct TreeNode {
+    key: i32,
+    left: Tree,
+    right: Tree,
+}
+
+/// Returns the node with the given key, inserting it if it doesn't already exist.
+fn find_or_insert(mut tree: &mut Tree, key: i32) -> &mut TreeNode {
+    loop {
+        let descended = maybe_borrow!(for<'x> |tree| -> &'x mut TreeNode {
+            if let Some(node) = &mut tree.root {
+                let next = match key.cmp(&node.key) {
+                    Ordering::Equal => return_borrowed!(node),
+                    Ordering::Less => &mut node.left,
+                    Ordering::Greater => &mut node.right,
+                };
+                continue_with!(tree, next, true);
+            }
+
+            false
+        });
+
+        if !descended {
+            return tree.root.insert(Box::new(TreeNode {
+                key,
+                left: Tree::default(),
+                right: Tree::default(),
+            }));
+        }
+    }
+}
+
+#[test]
+fn tree_insert() {
+    fn keys(tree: &Tree, out: &mut Vec<i32>) {
+        if let Some(node) = &tree.root {
+            keys(&node.left, out);
+            out.push(node.key);
+            keys(&node.right, out);
+        }
+    }
+
+    let mut tree = Tree::default();
+    for key in [5, 3, 8, 1, 4, 9, 3] {
+        find_or_insert(&mut tree, key);
+    }
+
+    let mut out = Vec::new();
+    keys(&tree, &mut out);
+    assert_eq!(out, [1, 3, 4, 5, 8, 9]);
+
+    assert_eq!(find_or_insert(&mut tree, 4).key, 4);
+}
+
+#[test]
+fn replace_one_of_several_pointers() {
+    /// Advances `a` until it reaches a node with the same value as `b`, returning both values.
+    /// If there is no such node, `b`'s value is negated instead.
+    fn find_matching<'a>(
+        mut a: &'a mut Node,
+        mut b: &'a mut Node,
+    ) -> Option<(&'a mut i32, &'a mut i32)> {
+        loop {
+            let advanced = maybe_borrow!(for<'x> |a, b| -> Option<(&'x mut i32, &'x mut i32)> {
+                if a.value == b.value {
+                    return_borrowed!(Some((&mut a.value, &mut b.value)));
+                }
+                if let Some(next) = &mut a.next {
+                    continue_with!(a, next, true);
+                }
+
+                false
+            });
+
+            // `b` is restored alongside the replaced `a`.
+            if !advanced {
+                b.value = -b.value;
+                return None;
+            }
+        }
+    }
+
+    let mut a = Node::from_values([1, 2, 3]).unwrap();
+    let mut b = Node::from_values([3]).unwrap();
+    let (x, y) = find_matching(&mut a, &mut b).unwrap();
+    *x += 1;
+    *y += 2;
+    assert_eq!(a.values(), [1, 2, 4]);
+    assert_eq!(b.values(), [5]);
+
+    assert!(find_matching(&mut a, &mut b).is_none());
+    assert_eq!(b.values(), [-5]);
+}
+
+#[test]
+// With `cfg(maybe_borrow_polonius)`, the block uses the pointer itself rather than its reborrow.
+#[cfg(not(maybe_borrow_polonius))]
+fn pointer_reborrowed_as_another_type() {
+    /// A pointer that reborrows as a plain mutable reference rather than as itself.
+    struct Handle<'a>(&'a mut Vec<i32>);
+
+    unsafe impl<'ptr> maybe_borrow::Reborrow<'ptr> for Handle<'ptr> {
+        type BorrowWithLifetime = maybe_borrow::WithLt!['b -> &'b mut Vec<i32>];
+
+        fn reborrow(this: &mut Self) -> &mut Vec<i32> {
+            this.0
+        }
+        unsafe fn extend<'b>(this: Self) -> &'b mut Vec<i32> {
+            unsafe { &mut *(this.0 as *mut Vec<i32>) }
+        }
+    }
+
+    /// Returns the first negative value, or pushes one if there isn't one.
+    fn first_negative(mut values: Handle<'_>) -> &mut i32 {
+        // Only blocks that use `continue_with!` need the reborrow to have the pointer's type.
+        maybe_borrow!(for<'x> |values| -> &'x mut i32 {
+            if let Some(value) = values.iter_mut().find(|v| **v < 0) {
+                return_borrowed!(value);
+            }
+        });
+
+        values.0.push(-1);
+        values.0.last_mut().unwrap()
+    }
+
+    let mut values = vec![1, -2];
+    *first_negative(Handle(&mut values)) -= 1;
+    assert_eq!(values, [1, -3]);
+
+    let mut values = vec![1];
+    *first_negative(Handle(&mut values)) -= 1;
+    assert_eq!(values, [1, -2]);
+}
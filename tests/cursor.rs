@@ -0,0 +1,183 @@
+use std::collections::BTreeMap;
+
+use maybe_borrow::CursorMut;
+
+#[derive(Debug)]
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+impl Node {
+    fn values(&self) -> Vec<i32> {
+        std::iter::successors(Some(self), |node| node.next.as_deref())
+            .map(|node| node.value)
+            .collect()
+    }
+}
+
+#[test]
+fn linked_list_push_back() {
+    fn push_back(list: &mut Node, value: i32) {
+        let last = CursorMut::new(list).descend(|node| node.next.as_deref_mut());
+        last.next = Some(Box::new(Node { value, next: None }));
+    }
+
+    let mut list = Node {
+        value: 1,
+        next: None,
+    };
+    push_back(&mut list, 2);
+    push_back(&mut list, 3);
+    assert_eq!(list.values(), [1, 2, 3]);
+}
+
+#[test]
+fn linked_list_find() {
+    let mut list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: Some(Box::new(Node {
+                value: 3,
+                next: None,
+            })),
+        })),
+    };
+
+    let found = CursorMut::new(&mut list)
+        .find(|node| node.value == 2, |node| node.next.as_deref_mut())
+        .unwrap();
+    found.value += 10;
+
+    let last = CursorMut::new(&mut list)
+        .find(|node| node.value == 4, |node| node.next.as_deref_mut())
+        .unwrap_err();
+    last.value += 10;
+
+    assert_eq!(list.values(), [1, 12, 13]);
+}
+
+#[test]
+fn step() {
+    let mut list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    };
+
+    let mut cursor = CursorMut::new(&mut list)
+        .step(|node| node.next.as_deref_mut())
+        .ok()
+        .unwrap();
+    cursor.get_mut().value += 10;
+
+    let cursor = cursor.step(|node| node.next.as_deref_mut()).err().unwrap();
+    assert_eq!(cursor.get().value, 12);
+}
+
+#[derive(Default)]
+struct Tree {
+    root: Option<Box<TreeNode>>,
+}
+
+struct TreeNode {
+    key: i32,
+    left: Tree,
+    right: Tree,
+}
+
+impl Tree {
+    fn keys(&self, out: &mut Vec<i32>) {
+        if let Some(node) = &self.root {
+            node.left.keys(out);
+            out.push(node.key);
+            node.right.keys(out);
+        }
+    }
+}
+
+/// Returns the node with the given key, inserting it if it doesn't already exist.
+fn find_or_insert(tree: &mut Tree, key: i32) -> &mut TreeNode {
+    let found = CursorMut::new(tree).find(
+        |tree| tree.root.as_ref().is_none_or(|node| node.key == key),
+        |tree| {
+            let node = tree.root.as_mut()?;
+            Some(match key < node.key {
+                true => &mut node.left,
+                false => &mut node.right,
+            })
+        },
+    );
+
+    // The predicate matches empty subtrees, so this is either the matching node or the empty
+    // subtree where it belongs.
+    let leaf = found.unwrap_or_else(|leaf| leaf);
+
+    leaf.root.get_or_insert_with(|| {
+        Box::new(TreeNode {
+            key,
+            left: Tree::default(),
+            right: Tree::default(),
+        })
+    })
+}
+
+#[test]
+fn binary_tree_insert() {
+    let mut tree = Tree::default();
+    for key in [5, 3, 8, 1, 4, 9, 3] {
+        assert_eq!(find_or_insert(&mut tree, key).key, key);
+    }
+
+    let mut out = Vec::new();
+    tree.keys(&mut out);
+    assert_eq!(out, [1, 3, 4, 5, 8, 9]);
+}
+
+#[derive(Default)]
+struct Trie {
+    children: BTreeMap<char, Trie>,
+    terminal: bool,
+}
+
+impl Trie {
+    fn insert(&mut self, key: &str) {
+        let mut chars = key.chars().peekable();
+
+        let mut node = CursorMut::new(self).descend(|node| {
+            let child = node.children.get_mut(chars.peek()?)?;
+            chars.next();
+            Some(child)
+        });
+
+        for c in chars {
+            node = node.children.entry(c).or_default();
+        }
+
+        node.terminal = true;
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        key.chars()
+            .try_fold(self, |node, c| node.children.get(&c))
+            .is_some_and(|node| node.terminal)
+    }
+}
+
+#[test]
+fn trie_insert() {
+    let mut trie = Trie::default();
+    trie.insert("car");
+    trie.insert("cart");
+    trie.insert("cat");
+
+    assert!(trie.contains("car"));
+    assert!(trie.contains("cart"));
+    assert!(trie.contains("cat"));
+    assert!(!trie.contains("ca"));
+    assert!(!trie.contains("cars"));
+    assert_eq!(trie.children.len(), 1);
+}
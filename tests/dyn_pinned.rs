@@ -0,0 +1,113 @@
+use std::{
+    any::Any,
+    future::Future,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+
+use futures::{prelude::*, task::noop_waker_ref};
+
+use maybe_borrow::prelude::*;
+
+type DynFuture<'a> = dyn Future<Output = Option<i32>> + 'a;
+type DynStream<'a> = dyn Stream<Item = i32> + Send + 'a;
+
+/// Polls `fut`, returning a reference to `slot` if it completes with a value, or `fut` itself if it
+/// completes with `None`.
+fn poll_into_slot<'a>(
+    mut fut: Pin<&'a mut DynFuture<'a>>,
+    slot: &'a mut Option<i32>,
+    cx: &mut Context,
+) -> Poll<Result<&'a mut i32, Pin<&'a mut DynFuture<'a>>>> {
+    maybe_borrow!(
+        for<'x> |fut| -> Poll<Result<&'x mut i32, Pin<&'x mut DynFuture<'a>>>> {
+            if let Some(x) = ready!(fut.as_mut().poll(cx)) {
+                return_borrowed!(Poll::Ready(Ok(slot.insert(x))));
+            }
+        }
+    );
+
+    Poll::Ready(Err(fut))
+}
+
+#[test]
+fn dyn_future() {
+    let cx = &mut Context::from_waker(noop_waker_ref());
+
+    let mut slot = None;
+    let mut fut = pin!(async { Some(1) });
+    let Poll::Ready(Ok(out)) = poll_into_slot(fut.as_mut(), &mut slot, cx) else {
+        panic!("expected borrowed value");
+    };
+    *out += 1;
+    assert_eq!(slot, Some(2));
+
+    let mut slot = None;
+    let mut fut = pin!(async { None });
+    let Poll::Ready(Err(_)) = poll_into_slot(fut.as_mut(), &mut slot, cx) else {
+        panic!("expected restored future");
+    };
+    assert_eq!(slot, None);
+
+    let mut slot = None;
+    let mut fut = pin!(future::pending());
+    assert!(poll_into_slot(fut.as_mut(), &mut slot, cx).is_pending());
+}
+
+/// Advances `stream` past its next even item, returning the stream, or `None` if the stream ends
+/// or is not ready.
+fn skip_past_even<'a>(
+    mut stream: Pin<&'a mut DynStream<'a>>,
+    cx: &mut Context,
+) -> Option<Pin<&'a mut DynStream<'a>>> {
+    loop {
+        try_maybe_borrow!(for<'x> |stream| -> Option<Pin<&'x mut DynStream<'a>>> {
+            let Poll::Ready(item) = stream.as_mut().poll_next(cx) else {
+                return_borrowed!(None);
+            };
+            if item? % 2 == 0 {
+                return_borrowed!(Some(stream));
+            }
+        });
+    }
+}
+
+#[test]
+fn dyn_stream() {
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut stream = pin!(stream::iter([1, 2, 3, 4, 5]));
+
+    let rest = skip_past_even(stream.as_mut(), cx).unwrap();
+    assert_eq!(rest.size_hint(), (3, Some(3)));
+    assert!(skip_past_even(stream.as_mut(), cx).is_some());
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+    assert!(skip_past_even(stream.as_mut(), cx).is_none());
+}
+
+#[test]
+fn dyn_boxed_stream() {
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut stream: Pin<Box<DynStream>> = Box::pin(stream::iter([1, 3, 5, 6, 7]));
+
+    assert!(skip_past_even(stream.as_mut(), cx).is_some());
+    assert_eq!(stream.size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn dyn_any_pinned_shared() {
+    fn downcast(mut value: Pin<&dyn Any>) -> Result<&i32, Pin<&dyn Any>> {
+        maybe_borrow!(for<'x> |value| -> Result<&'x i32, Pin<&'x dyn Any>> {
+            if let Some(x) = value.get_ref().downcast_ref::<i32>() {
+                return_borrowed!(Ok(x));
+            }
+        });
+
+        Err(value)
+    }
+
+    let x = pin!(1);
+    assert!(matches!(downcast(x.as_ref()), Ok(&1)));
+
+    let y = pin!("abc");
+    assert!(downcast(y.as_ref()).unwrap_err().is::<&str>());
+}
@@ -0,0 +1,98 @@
+use maybe_borrow::prelude::*;
+
+/// A lending iterator written with a plain GAT, without any implied-bound workarounds.
+trait LendingIterator {
+    type Item<'a>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+}
+
+/// Yields the growing prefixes of a borrowed slice.
+struct Prefixes<'s, T> {
+    rest: &'s mut [T],
+    prefix: Vec<&'s mut T>,
+}
+
+impl<'s, T> Prefixes<'s, T> {
+    fn new(src: &'s mut [T]) -> Self {
+        Self {
+            rest: src,
+            prefix: Vec::new(),
+        }
+    }
+}
+
+impl<'s, T> LendingIterator for Prefixes<'s, T> {
+    type Item<'a>
+        = &'a mut Vec<&'s mut T>
+    where
+        Self: 'a;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        let (first, rest) = std::mem::take(&mut self.rest).split_first_mut()?;
+        self.rest = rest;
+        self.prefix.push(first);
+        Some(&mut self.prefix)
+    }
+}
+
+fn nth<I: LendingIterator>(mut iter: &mut I, mut n: usize) -> Option<I::Item<'_>> {
+    loop {
+        maybe_borrow!(for<'x> |iter| -> Option<I::Item<'x>> {
+            let item = iter.next();
+            if n == 0 || item.is_none() {
+                return_borrowed!(item);
+            }
+        });
+        n -= 1;
+    }
+}
+
+fn nth_with_try<I: LendingIterator>(mut iter: &mut I, mut n: usize) -> Option<I::Item<'_>> {
+    loop {
+        try_maybe_borrow!(for<'x> |iter| -> Option<I::Item<'x>> {
+            let item = iter.next()?;
+            if n == 0 {
+                return_borrowed!(Some(item));
+            }
+        });
+        n -= 1;
+    }
+}
+
+fn nth_with_match<I: LendingIterator>(mut iter: &mut I, mut n: usize) -> Option<I::Item<'_>> {
+    loop {
+        maybe_borrow_match!(for<'x> |iter| -> Option<I::Item<'x>> {
+            match iter.next() {
+                Some(_) if n > 0 => continue,
+                item => borrow item,
+            }
+        });
+        n -= 1;
+    }
+}
+
+#[test]
+fn borrowed_iterator() {
+    let mut values = [1, 2, 3, 4];
+    let mut iter = Prefixes::new(&mut values);
+
+    for x in nth(&mut iter, 1).unwrap() {
+        **x += 10;
+    }
+    for x in nth_with_try(&mut iter, 0).unwrap() {
+        **x += 10;
+    }
+    assert_eq!(
+        nth_with_match(&mut iter, 0).map(|prefix| prefix.len()),
+        Some(4)
+    );
+    assert!(nth(&mut iter, 0).is_none());
+    assert!(nth_with_try(&mut iter, 0).is_none());
+    assert!(nth_with_match(&mut iter, 0).is_none());
+    drop(iter);
+
+    assert_eq!(values, [21, 22, 13, 4]);
+}
@@ -0,0 +1,109 @@
+use std::{collections::HashMap, task::Poll};
+
+use maybe_borrow::prelude::*;
+
+/// Returns the first value, or pushes one if there isn't one.
+fn first_or_push(mut values: &mut Vec<i32>) -> &mut i32 {
+    maybe_borrow!(for<'x> |values| -> &'x mut _ {
+        if let Some(first) = values.first_mut() {
+            return_borrowed!(first);
+        }
+    });
+
+    values.push(0);
+    values.last_mut().unwrap()
+}
+
+#[test]
+fn reference() {
+    let mut values = vec![];
+    *first_or_push(&mut values) += 1;
+    *first_or_push(&mut values) += 1;
+    assert_eq!(values, [2]);
+}
+
+/// Returns the values for `key` in both maps, adding it to the first map if it isn't in both.
+fn both<'a, 'b>(
+    mut a: &'a mut HashMap<String, Vec<u8>>,
+    mut b: &'b mut HashMap<String, Vec<u16>>,
+    key: &str,
+) -> Option<(&'a mut Vec<u8>, &'b mut Vec<u16>)> {
+    maybe_borrow!(for<'x, 'y> |a, b| -> Option<(&'x mut _, &'y mut _)> {
+        if let (Some(a), Some(b)) = (a.get_mut(key), b.get_mut(key)) {
+            return_borrowed!(Some((a, b)));
+        }
+    });
+
+    a.entry(key.into()).or_default();
+    None
+}
+
+#[test]
+fn multiple_lifetimes() {
+    let mut a = HashMap::from([("k".to_string(), vec![1])]);
+    let mut b = HashMap::new();
+    assert!(both(&mut a, &mut b, "k").is_none());
+
+    b.insert("k".to_string(), vec![2]);
+    let (a_values, b_values) = both(&mut a, &mut b, "k").unwrap();
+    a_values.push(3);
+    b_values.push(4);
+    assert_eq!((&a["k"][..], &b["k"][..]), (&[1, 3][..], &[2, 4][..]));
+}
+
+/// Returns the first value that's ready, or `Pending` if the first value isn't ready.
+fn first_ready(mut values: &mut [Poll<String>]) -> Poll<Option<Result<&mut String, ()>>> {
+    try_maybe_borrow!(for<'x> |values| -> Poll<Option<Result<&'x mut _, ()>>> {
+        let first = ready_some!(Poll::Ready(values.first_mut()));
+        if first.is_pending() {
+            return_borrowed!(Poll::Pending);
+        }
+        for value in values.iter_mut() {
+            if let Poll::Ready(value) = value {
+                return_ready!(Some(Ok(value)));
+            }
+        }
+    });
+
+    Poll::Ready(None)
+}
+
+#[test]
+fn try_maybe_borrow() {
+    assert_eq!(first_ready(&mut []), Poll::Ready(None));
+    assert_eq!(first_ready(&mut [Poll::Pending]), Poll::Pending);
+    assert_eq!(
+        first_ready(&mut [Poll::Ready("a".into())]),
+        Poll::Ready(Some(Ok(&mut "a".into())))
+    );
+}
+
+/// Returns the smaller value, or `b` if they're equal.
+fn min<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+    maybe_borrow!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> &'z mut _ {
+        if *a < *b {
+            return_borrowed!(a);
+        }
+    });
+
+    b
+}
+
+#[test]
+fn bounded() {
+    assert_eq!(*min(&mut 1, &mut 2), 1);
+    assert_eq!(*min(&mut 2, &mut 2), 2);
+}
+
+#[test]
+fn expression() {
+    let mut storage = vec![1, 2];
+    let mut values = &mut storage;
+    let outcome = maybe_borrow_expr!(for<'x> |values| -> &'x mut _ {
+        if let Some(last) = values.last_mut() {
+            return_borrowed!(last);
+        }
+    });
+    *outcome.borrowed().unwrap() += 1;
+    assert_eq!(storage, [1, 3]);
+}
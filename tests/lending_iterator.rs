@@ -1,3 +1,5 @@
+#![allow(clippy::len_zero)]
+
 use maybe_borrow::prelude::*;
 
 trait LendingIteratorBase<'iter, _Bound = &'iter Self>: 'iter {
@@ -116,6 +118,20 @@ fn next_filtered_with_try<'iter, I: LendingIterator>(
     }
 }
 
+fn next_filtered_with_match<'iter, I: LendingIterator>(
+    mut iter: &'iter mut I,
+    mut predicate: impl FnMut(&I::Item<'_>) -> bool,
+) -> Option<I::Item<'iter>> {
+    loop {
+        maybe_borrow_match!(for<'x> |iter| -> Option<I::Item<'x>> {
+            match iter.next() {
+                Some(ref item) if !predicate(item) => continue,
+                out => borrow out,
+            }
+        });
+    }
+}
+
 #[test]
 fn test_next_filtered() {
     let mut iter = Windows::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 3);
@@ -138,3 +154,14 @@ fn test_next_filtered_with_try() {
 
     assert_eq!(items, [[1, 2, 3], [3, 4, 5], [5, 6, 7], [7, 8, 9],])
 }
+
+#[test]
+fn test_next_filtered_with_match() {
+    let mut iter = Windows::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 3);
+
+    let items = Vec::from_iter(std::iter::from_fn(move || {
+        next_filtered_with_match(&mut iter, |x| x[0] % 2 != 0).map(|x| x.to_vec())
+    }));
+
+    assert_eq!(items, [[1, 2, 3], [3, 4, 5], [5, 6, 7], [7, 8, 9],])
+}
@@ -1,3 +1,8 @@
+// With `cfg(maybe_borrow_polonius)`, the block uses the pinned pointer itself, so it can't be
+// consumed by `poll_next` on the path that loops, and reborrowing it with `as_mut` instead would
+// borrow the local variable rather than the stream.
+#![cfg(not(maybe_borrow_polonius))]
+
 use std::{
     pin::{pin, Pin},
     task::{ready, Context, Poll},
@@ -122,6 +127,36 @@ fn poll_next_filtered_with_try<'iter, I: LendingStream>(
     }
 }
 
+fn poll_next_filtered_with_return_ready<'iter, I: LendingStream>(
+    mut iter: Pin<&'iter mut I>,
+    mut predicate: impl FnMut(&I::Item<'_>) -> bool,
+    cx: &mut Context,
+) -> Poll<Option<I::Item<'iter>>> {
+    loop {
+        maybe_borrow!(for<'x> |iter| -> Poll<Option<I::Item<'x>>> {
+            match ready!(iter.poll_next(cx)) {
+                Some(ref item) if !predicate(item) => {}
+                out => return_ready!(out),
+            }
+        });
+    }
+}
+
+fn poll_next_filtered_with_ready_some<'iter, I: LendingStream>(
+    mut iter: Pin<&'iter mut I>,
+    mut predicate: impl FnMut(&I::Item<'_>) -> bool,
+    cx: &mut Context,
+) -> Poll<Option<I::Item<'iter>>> {
+    loop {
+        maybe_borrow!(for<'x> |iter| -> Poll<Option<I::Item<'x>>> {
+            let item = ready_some!(iter.poll_next(cx));
+            if predicate(&item) {
+                return_ready!(Some(item));
+            }
+        });
+    }
+}
+
 #[test]
 fn test_next_filtered() {
     block_on(async {
@@ -159,3 +194,41 @@ fn test_next_filtered_with_try() {
         assert_eq!(items, [[1, 2, 3], [3, 4, 5], [5, 6, 7], [7, 8, 9]])
     });
 }
+
+#[test]
+fn test_next_filtered_with_return_ready() {
+    block_on(async {
+        let mut stream = pin!(Windows::new(
+            stream::iter([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
+            3
+        ));
+
+        let items = futures::stream::poll_fn(move |cx| {
+            poll_next_filtered_with_return_ready(stream.as_mut(), |x| x[0] % 2 != 0, cx)
+                .map(|x| x.map(|x| x.to_vec()))
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(items, [[1, 2, 3], [3, 4, 5], [5, 6, 7], [7, 8, 9]])
+    });
+}
+
+#[test]
+fn test_next_filtered_with_ready_some() {
+    block_on(async {
+        let mut stream = pin!(Windows::new(
+            stream::iter([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]),
+            3
+        ));
+
+        let items = futures::stream::poll_fn(move |cx| {
+            poll_next_filtered_with_ready_some(stream.as_mut(), |x| x[0] % 2 != 0, cx)
+                .map(|x| x.map(|x| x.to_vec()))
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(items, [[1, 2, 3], [3, 4, 5], [5, 6, 7], [7, 8, 9]])
+    });
+}
@@ -0,0 +1,128 @@
+use maybe_borrow::{prelude::*, Outcome};
+
+#[test]
+fn smaller_of_two() {
+    /// Returns the smaller of the two values, or `b` if they're equal.
+    fn min<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+        maybe_borrow!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> &'z mut i32 {
+            if *a < *b {
+                return_borrowed!(a);
+            }
+        });
+
+        // Both pointers are usable again after the block continues.
+        *a += 0;
+        b
+    }
+
+    let (mut a, mut b) = (1, 2);
+    *min(&mut a, &mut b) += 10;
+    assert_eq!((a, b), (11, 2));
+
+    *min(&mut a, &mut b) += 10;
+    assert_eq!((a, b), (11, 12));
+}
+
+#[test]
+fn borrowing_from_both() {
+    /// Returns both values if they're equal, or the larger one otherwise.
+    fn larger<'a: 'c, 'b: 'c, 'c>(
+        mut a: &'a mut i32,
+        mut b: &'b mut i32,
+    ) -> Result<(&'c mut i32, &'c mut i32), &'c mut i32> {
+        maybe_borrow!(
+            for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> Result<(&'z mut i32, &'z mut i32), &'z mut i32> {
+                if *a == *b {
+                    return_borrowed!(Ok((a, b)));
+                }
+            }
+        );
+
+        Err(if *a > *b { a } else { b })
+    }
+
+    let (mut a, mut b) = (1, 1);
+    let (x, y) = larger(&mut a, &mut b).unwrap();
+    *x += 1;
+    *y += 2;
+    assert_eq!((a, b), (2, 3));
+
+    *larger(&mut a, &mut b).unwrap_err() += 10;
+    assert_eq!((a, b), (2, 13));
+}
+
+#[test]
+fn three_pointers() {
+    /// Returns `a` or `b` if either is smaller than `c`, or `c` otherwise.
+    fn below<'a: 'd, 'b: 'd, 'c: 'd, 'd>(
+        mut a: &'a mut i32,
+        mut b: &'b mut i32,
+        mut c: &'c mut i32,
+    ) -> &'d mut i32 {
+        maybe_borrow!(
+            for<'x, 'y, 'w, 'z where 'x: 'z, 'y: 'z, 'w: 'z> |a, b, c| -> &'z mut i32 {
+                if *a < *c {
+                    return_borrowed!(a);
+                }
+                if *b < *c {
+                    return_borrowed!(b);
+                }
+            }
+        );
+
+        *a += 1;
+        *b += 1;
+        c
+    }
+
+    let (mut a, mut b, mut c) = (3, 2, 1);
+    *below(&mut a, &mut b, &mut c) += 10;
+    assert_eq!((a, b, c), (4, 3, 11));
+
+    *below(&mut a, &mut b, &mut c) += 10;
+    assert_eq!((a, b, c), (14, 3, 11));
+}
+
+#[test]
+fn single_pointer_lifetime() {
+    /// Returns the first value in `src`, or `fallback` after pushing to `src` if it's empty.
+    fn first_or<'a: 'c, 'c>(mut src: &'a mut Vec<i32>, fallback: &'c mut i32) -> &'c mut i32 {
+        maybe_borrow!(for<'x, 'z where 'x: 'z> |src| -> &'z mut i32 {
+            if let Some(first) = src.first_mut() {
+                return_borrowed!(first);
+            }
+        });
+
+        src.push(0);
+        fallback
+    }
+
+    let (mut values, mut fallback) = (vec![], 0);
+    *first_or(&mut values, &mut fallback) += 1;
+    assert_eq!((&values[..], fallback), (&[0][..], 1));
+
+    *first_or(&mut values, &mut fallback) += 1;
+    assert_eq!((&values[..], fallback), (&[1][..], 1));
+}
+
+#[test]
+fn expression() {
+    fn min_or_sum<'a: 'c, 'b: 'c, 'c>(
+        mut a: &'a mut i32,
+        mut b: &'b mut i32,
+    ) -> Outcome<&'c mut i32, i32> {
+        maybe_borrow_expr!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> &'z mut i32 {
+            if *a < *b {
+                return_borrowed!(a);
+            }
+            if *b < *a {
+                return_borrowed!(b);
+            }
+        } else |()| *a + *b)
+    }
+
+    let (mut a, mut b) = (1, 2);
+    assert_eq!(min_or_sum(&mut a, &mut b).borrowed().copied(), Some(1));
+    b = 1;
+    assert_eq!(min_or_sum(&mut a, &mut b).restored(), Some(2));
+}
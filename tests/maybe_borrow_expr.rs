@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use maybe_borrow::{prelude::*, Outcome};
+
+#[test]
+fn borrowed_and_restored() {
+    fn find(mut src: &mut Vec<i32>, value: i32) -> Outcome<&mut i32, usize> {
+        maybe_borrow_expr!(for<'x> |src| -> &'x mut i32 {
+            if let Some(i) = src.iter().position(|x| *x == value) {
+                return_borrowed!(&mut src[i]);
+            }
+            src.len()
+        })
+    }
+
+    let mut values = vec![1, 2, 3];
+    *find(&mut values, 2).borrowed().unwrap() += 10;
+    assert_eq!(find(&mut values, 5), Outcome::Restored(3));
+    assert_eq!(values, [1, 12, 3]);
+}
+
+#[test]
+fn else_clause_uses_restored_pointers() {
+    fn get_or_push(mut src: &mut Vec<i32>, value: i32) -> &mut i32 {
+        maybe_borrow_expr!(for<'x> |src| -> &'x mut i32 {
+            if let Some(i) = src.iter().position(|x| *x == value) {
+                return_borrowed!(&mut src[i]);
+            }
+        } else |()| {
+            src.push(value);
+            src.last_mut().unwrap()
+        })
+        .into_inner()
+    }
+
+    let mut values = vec![1, 2];
+    *get_or_push(&mut values, 2) += 10;
+    *get_or_push(&mut values, 3) += 10;
+    assert_eq!(values, [1, 12, 13]);
+}
+
+#[test]
+fn inside_closure() {
+    let mut map = HashMap::from([("a", 1), ("b", 2)]);
+
+    // Post-processes the borrowed value in a closure, which can't return from the test function.
+    let mut bump = |key: &str| {
+        let mut map = &mut map;
+        maybe_borrow_expr!(for<'x> |map| -> &'x mut i32 {
+            if let Some(value) = map.get_mut(key) {
+                return_borrowed!(value);
+            }
+        } else |()| {
+            map.clear();
+        })
+        .borrowed()
+        .map(|value| {
+            *value += 10;
+            *value
+        })
+    };
+
+    assert_eq!(bump("a"), Some(11));
+    assert_eq!(bump("a"), Some(21));
+    assert_eq!(bump("c"), None);
+    assert!(map.is_empty());
+}
+
+#[test]
+fn continue_with_in_else_clause() {
+    #[derive(Debug)]
+    struct Node {
+        value: i32,
+        next: Option<Box<Node>>,
+    }
+
+    /// Returns the value of `node` if it matches, or otherwise whether it was advanced to its
+    /// successor.
+    fn step(mut node: &mut Node, value: i32) -> Outcome<&mut i32, (bool, &mut Node)> {
+        maybe_borrow_expr!(for<'x> |node| -> &'x mut i32 {
+            if node.value == value {
+                return_borrowed!(&mut node.value);
+            }
+            if let Some(next) = &mut node.next {
+                continue_with!(node, next, true);
+            }
+
+            false
+        } else |advanced| (advanced, node))
+    }
+
+    fn find(mut node: &mut Node, value: i32) -> Result<&mut i32, &mut Node> {
+        loop {
+            match step(node, value) {
+                Outcome::Borrowed(value) => return Ok(value),
+                Outcome::Restored((true, next)) => node = next,
+                Outcome::Restored((false, last)) => return Err(last),
+            }
+        }
+    }
+
+    let mut list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    };
+
+    *find(&mut list, 2).unwrap() += 10;
+    assert_eq!(find(&mut list, 3).unwrap_err().value, 12);
+}
+
+#[test]
+fn multiple_lifetimes_with_carry() {
+    fn swap<'a, 'b>(
+        mut a: &'a mut HashMap<String, i32>,
+        mut b: &'b mut HashMap<String, i32>,
+        mut key: String,
+    ) -> Result<i32, String> {
+        let outcome = maybe_borrow_expr!(for<'x, 'y> |a, b, move key| -> (&'x mut i32, &'y mut i32) {
+            if let (Some(x), Some(y)) = (a.get_mut(&key), b.get_mut(&key)) {
+                return_borrowed!((x, y));
+            }
+        } else |()| {
+            a.insert(key.clone(), 0);
+            key
+        });
+
+        match outcome {
+            Outcome::Borrowed((x, y)) => {
+                std::mem::swap(x, y);
+                Ok(*x)
+            }
+            Outcome::Restored(key) => Err(key),
+        }
+    }
+
+    let mut a = HashMap::from([("k".to_owned(), 1)]);
+    let mut b = HashMap::from([("k".to_owned(), 2)]);
+    assert_eq!(swap(&mut a, &mut b, "k".into()), Ok(2));
+    assert_eq!(b["k"], 1);
+    assert_eq!(swap(&mut a, &mut b, "j".into()), Err("j".into()));
+    assert_eq!(a["j"], 0);
+}
+
+#[test]
+fn coerced_pointer() {
+    fn first_over(values: &mut [i32], min: i32) -> Result<&mut i32, usize> {
+        let outcome = maybe_borrow_expr!(for<'x> |slice: &mut [i32] = &mut values[..]| -> &'x mut i32 {
+            if let Some(value) = slice.iter_mut().find(|value| **value > min) {
+                return_borrowed!(value);
+            }
+        } else |()| slice.len());
+
+        match outcome {
+            Outcome::Borrowed(value) => Ok(value),
+            Outcome::Restored(len) => Err(len),
+        }
+    }
+
+    let mut values = vec![1, 5];
+    *first_over(&mut values, 2).unwrap() += 1;
+    assert_eq!(values, [1, 6]);
+    assert_eq!(first_over(&mut values, 10), Err(2));
+}
@@ -0,0 +1,109 @@
+use std::collections::VecDeque;
+
+use maybe_borrow::prelude::*;
+
+/// Parses a body prefixed by a fixed-size header, whose last byte is the body's length.
+enum Parser {
+    Header { remaining: usize },
+    Body { buf: Vec<u8>, len: usize },
+    Done { body: Vec<u8> },
+}
+
+/// Feeds bytes from `input` into the parser, returning the body once it has been read.
+///
+/// Each state either borrows out of the parser or falls through to consume more input.
+fn feed<'a>(mut parser: &'a mut Parser, input: &mut VecDeque<u8>) -> Option<&'a [u8]> {
+    loop {
+        let byte = maybe_borrow_match!(for<'x> |parser| -> Option<&'x [u8]> {
+            match parser {
+                Parser::Done { body } => borrow Some(body),
+                Parser::Body { buf, len } if buf.len() == *len => continue {
+                    let body = std::mem::take(buf);
+                    *parser = Parser::Done { body };
+                    // Loop around to borrow the finished body.
+                    None
+                }
+                Parser::Header { .. } | Parser::Body { .. } => continue match input.pop_front() {
+                    Some(byte) => Some(byte),
+                    None => return_borrowed!(None),
+                },
+            }
+        });
+
+        let Some(byte) = byte else {
+            continue;
+        };
+
+        match parser {
+            Parser::Header { remaining: 1 } => {
+                *parser = Parser::Body {
+                    buf: Vec::new(),
+                    len: byte.into(),
+                }
+            }
+            Parser::Header { remaining } => *remaining -= 1,
+            Parser::Body { buf, .. } => buf.push(byte),
+            Parser::Done { .. } => unreachable!(),
+        }
+    }
+}
+
+#[test]
+fn state_machine() {
+    let mut parser = Parser::Header { remaining: 2 };
+    let mut input = VecDeque::from([0]);
+    assert_eq!(feed(&mut parser, &mut input), None);
+
+    input.extend([2, 5, 6, 7]);
+    assert_eq!(feed(&mut parser, &mut input), Some(&[5, 6][..]));
+    assert_eq!(input, [7]);
+    assert_eq!(feed(&mut parser, &mut input), Some(&[5, 6][..]));
+}
+
+#[test]
+fn carry_and_continue_with() {
+    struct Node {
+        value: i32,
+        next: Option<Box<Node>>,
+    }
+
+    /// Appends `values` after the first node with a negative value, or the last node.
+    fn insert_after_negative(mut node: &mut Node, mut values: Vec<i32>) {
+        loop {
+            let advanced = maybe_borrow_match!(for<'x> |node, move values| -> () {
+                match &mut node.next {
+                    _ if node.value < 0 => continue false,
+                    Some(next) => continue {
+                        continue_with!(node, next, true);
+                    }
+                    None => continue false,
+                }
+            });
+
+            if !advanced {
+                for value in values.into_iter().rev() {
+                    let next = node.next.take();
+                    node.next = Some(Box::new(Node { value, next }));
+                }
+                return;
+            }
+        }
+    }
+
+    let mut list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: -2,
+            next: Some(Box::new(Node {
+                value: 3,
+                next: None,
+            })),
+        })),
+    };
+
+    insert_after_negative(&mut list, vec![4, 5]);
+    let values: Vec<_> = std::iter::successors(Some(&list), |node| node.next.as_deref())
+        .map(|node| node.value)
+        .collect();
+    assert_eq!(values, [1, -2, 4, 5, 3]);
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use maybe_borrow::prelude::*;
+
+#[test]
+fn two_levels_same_pointer() {
+    /// Returns the first negative value, the first value if there are several, or a pushed zero.
+    fn pick(mut src: &mut Vec<i32>) -> &mut i32 {
+        maybe_borrow!(for<'x> |src| -> &'x mut i32 {
+            maybe_borrow!(for<'x> |src| -> &'x mut i32 {
+                if let Some(x) = src.iter_mut().find(|x| **x < 0) {
+                    return_borrowed!(x);
+                }
+            });
+
+            // `src` is usable again after the inner block continues.
+            if src.len() > 1 {
+                return_borrowed!(&mut src[0]);
+            }
+        });
+
+        src.push(0);
+        src.last_mut().unwrap()
+    }
+
+    let mut values = vec![1, -2];
+    *pick(&mut values) -= 1;
+    assert_eq!(values, [1, -3]);
+
+    let mut values = vec![1, 2];
+    *pick(&mut values) += 10;
+    assert_eq!(values, [11, 2]);
+
+    let mut values = vec![1];
+    *pick(&mut values) += 10;
+    assert_eq!(values, [1, 10]);
+}
+
+#[test]
+fn three_levels_different_pointers() {
+    /// Looks `key` up in `a`, then `b`, then `c`, inserting it into `a` if it's missing.
+    fn lookup<'a>(
+        mut a: &'a mut HashMap<String, i32>,
+        mut b: &'a mut HashMap<String, i32>,
+        mut c: &'a mut HashMap<String, i32>,
+        key: &str,
+    ) -> &'a mut i32 {
+        maybe_borrow!(for<'x> |a, b, c| -> &'x mut i32 {
+            maybe_borrow!(for<'x> |b, c| -> &'x mut i32 {
+                maybe_borrow!(for<'x> |c| -> &'x mut i32 {
+                    if let Some(x) = c.get_mut(key) {
+                        if b.contains_key(key) {
+                            // Shadowed by `b`.
+                        } else {
+                            return_borrowed!(x);
+                        }
+                    }
+                });
+
+                if let Some(x) = b.get_mut(key) {
+                    return_borrowed!(x);
+                }
+                c.clear();
+            });
+
+            if let Some(x) = a.get_mut(key) {
+                return_borrowed!(x);
+            }
+        });
+
+        a.entry(key.to_owned()).or_default()
+    }
+
+    let mut a = HashMap::from([("a".to_owned(), 1)]);
+    let mut b = HashMap::from([("b".to_owned(), 2)]);
+    let mut c = HashMap::from([("b".to_owned(), 3), ("c".to_owned(), 4)]);
+
+    *lookup(&mut a, &mut b, &mut c, "c") += 10;
+    assert_eq!(c["c"], 14);
+    *lookup(&mut a, &mut b, &mut c, "b") += 10;
+    assert_eq!((b["b"], c["b"]), (12, 3));
+
+    // Falling through the innermost blocks clears `c` before `a` is checked.
+    *lookup(&mut a, &mut b, &mut c, "a") += 10;
+    assert_eq!(a["a"], 11);
+    assert!(c.is_empty());
+
+    *lookup(&mut a, &mut b, &mut c, "d") += 10;
+    assert_eq!(a["d"], 10);
+}
+
+#[test]
+fn inside_try_maybe_borrow() {
+    fn first_even(mut values: &mut Vec<i32>) -> Option<&mut i32> {
+        try_maybe_borrow!(for<'x> |values| -> Option<&'x mut i32> {
+            let negative = *values.first()? < 0;
+            maybe_borrow!(for<'x> |values| -> Option<&'x mut i32> {
+                if let Some(x) = values.iter_mut().find(|x| **x % 2 == 0) {
+                    return_borrowed!(Some(x));
+                }
+            });
+            if negative {
+                return_borrowed!(None);
+            }
+        });
+
+        values.push(0);
+        values.last_mut()
+    }
+
+    assert_eq!(first_even(&mut vec![1, 2]), Some(&mut 2));
+    assert_eq!(first_even(&mut vec![-1]), None);
+
+    assert_eq!(first_even(&mut vec![]), None);
+
+    let mut values = vec![1];
+    assert_eq!(first_even(&mut values), Some(&mut 0));
+    assert_eq!(values, [1, 0]);
+}
+
+#[test]
+fn inside_maybe_borrow_expr() {
+    use maybe_borrow::Outcome;
+
+    fn find(mut src: &mut Vec<i32>, value: i32) -> Outcome<&mut i32, usize> {
+        maybe_borrow_expr!(for<'x> |src| -> &'x mut i32 {
+            maybe_borrow!(for<'x> |src| -> &'x mut i32 {
+                if let Some(x) = src.iter_mut().find(|x| **x == value) {
+                    return_borrowed!(x);
+                }
+            });
+            src.len()
+        })
+    }
+
+    let mut values = vec![1, 2];
+    *find(&mut values, 2).borrowed().unwrap() += 10;
+    assert_eq!(find(&mut values, 3), Outcome::Restored(2));
+    assert_eq!(values, [1, 12]);
+}
+
+#[test]
+fn unannotated_closure() {
+    // Nothing but the trailing `None` gives the closure's return type.
+    let has_negative = move |mut values: &mut Vec<i32>| {
+        maybe_borrow!(for<'x> |values| -> Option<()> {
+            maybe_borrow!(for<'x> |values| -> Option<()> {
+                if values.iter().any(|x| *x < 0) {
+                    return_borrowed!(Some(()));
+                }
+            });
+            values.push(-1);
+        });
+        None
+    };
+
+    assert!(has_negative(&mut vec![1, -2]).is_some());
+
+    let mut values = vec![1];
+    assert!(has_negative(&mut values).is_none());
+    assert_eq!(values, [1, -1]);
+}
@@ -0,0 +1,196 @@
+// With `cfg(maybe_borrow_polonius)`, blocks use the original pointer without reborrowing it, so
+// the assertions about reborrows are skipped.
+
+use std::{
+    cell::Cell,
+    mem::ManuallyDrop,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use maybe_borrow::{prelude::*, Reborrow};
+
+#[derive(Default)]
+struct Drops {
+    originals: Cell<usize>,
+    reborrows: Cell<usize>,
+}
+
+/// A pointer with drop glue that records how many times it was dropped.
+struct Tracked<'a> {
+    value: &'a mut i32,
+    drops: &'a Drops,
+    original: bool,
+}
+
+impl<'a> Tracked<'a> {
+    fn new(value: &'a mut i32, drops: &'a Drops) -> Self {
+        Self {
+            value,
+            drops,
+            original: true,
+        }
+    }
+
+    /// Consumes the pointer without recording a drop.
+    fn into_value(self) -> &'a mut i32 {
+        let this = ManuallyDrop::new(self);
+        unsafe { std::ptr::read(&this.value) }
+    }
+}
+
+impl Drop for Tracked<'_> {
+    fn drop(&mut self) {
+        let count = match self.original {
+            true => &self.drops.originals,
+            false => &self.drops.reborrows,
+        };
+        count.set(count.get() + 1);
+    }
+}
+
+unsafe impl<'ptr> Reborrow<'ptr> for Tracked<'ptr> {
+    type BorrowWithLifetime = maybe_borrow::WithLt!['b -> Tracked<'b>];
+
+    fn reborrow<'b>(this: &'b mut Self) -> Tracked<'b> {
+        Tracked {
+            value: this.value,
+            drops: this.drops,
+            original: false,
+        }
+    }
+
+    unsafe fn extend<'b>(this: Self) -> Tracked<'b> {
+        let original = this.original;
+        let drops = unsafe { &*(this.drops as *const Drops) };
+        let value = unsafe { &mut *(this.into_value() as *mut i32) };
+        Tracked {
+            value,
+            drops,
+            original,
+        }
+    }
+}
+
+fn increment_or_panic(mut ptr: Tracked<'_>) -> Result<&mut i32, Tracked<'_>> {
+    maybe_borrow!(for<'x> |ptr| -> Result<&'x mut i32, Tracked<'x>> {
+        match *ptr.value {
+            0 => panic!("expected panic"),
+            1 => {}
+            _ => return_borrowed!(Ok(ptr.into_value())),
+        }
+        *ptr.value += 1;
+    });
+
+    Err(ptr)
+}
+
+#[test]
+fn unwind_drops_pointer_once() {
+    let drops = Drops::default();
+    let mut value = 0;
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _ = increment_or_panic(Tracked::new(&mut value, &drops));
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(drops.originals.get(), 1);
+    #[cfg(not(maybe_borrow_polonius))]
+    assert_eq!(drops.reborrows.get(), 1);
+
+    // The target is still usable and was not modified after the panic.
+    value += 1;
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn continue_returns_pointer_without_dropping() {
+    let drops = Drops::default();
+    let mut value = 1;
+
+    let Err(ptr) = increment_or_panic(Tracked::new(&mut value, &drops)) else {
+        panic!("expected restored pointer");
+    };
+    assert!(ptr.original);
+    assert_eq!(*ptr.value, 2);
+    assert_eq!(drops.originals.get(), 0);
+    #[cfg(not(maybe_borrow_polonius))]
+    assert_eq!(drops.reborrows.get(), 1);
+
+    drop(ptr);
+    assert_eq!(drops.originals.get(), 1);
+}
+
+#[test]
+fn break_forgets_pointer() {
+    let drops = Drops::default();
+    let mut value = 2;
+
+    let Ok(out) = increment_or_panic(Tracked::new(&mut value, &drops)) else {
+        panic!("expected borrowed value");
+    };
+    *out += 1;
+
+    assert_eq!(drops.originals.get(), 0);
+    assert_eq!(drops.reborrows.get(), 0);
+    assert_eq!(value, 3);
+}
+
+#[test]
+fn unwind_drops_each_pointer_once() {
+    let drops_a = Drops::default();
+    let drops_b = Drops::default();
+    let (mut a, mut b) = (0, 0);
+
+    fn panicking<'a, 'b>(mut a: Tracked<'a>, mut b: Tracked<'b>) {
+        maybe_borrow!(for<'x, 'y> |a, b| -> () {
+            *a.value += 1;
+            *b.value += 1;
+            if *a.value > 0 {
+                panic!("expected panic");
+            }
+        });
+    }
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        panicking(
+            Tracked::new(&mut a, &drops_a),
+            Tracked::new(&mut b, &drops_b),
+        );
+    }));
+
+    assert!(result.is_err());
+    for drops in [&drops_a, &drops_b] {
+        assert_eq!(drops.originals.get(), 1);
+        #[cfg(not(maybe_borrow_polonius))]
+        assert_eq!(drops.reborrows.get(), 1);
+    }
+    assert_eq!((a, b), (1, 1));
+}
+
+#[test]
+fn replace_forgets_pointer() {
+    fn replace(mut ptr: Tracked<'_>) -> Tracked<'_> {
+        maybe_borrow!(for<'x> |ptr| -> Tracked<'x> {
+            *ptr.value += 1;
+            if *ptr.value > 0 {
+                continue_with!(ptr, ptr);
+            }
+        });
+        ptr
+    }
+
+    let drops = Drops::default();
+    let mut value = 0;
+
+    let ptr = replace(Tracked::new(&mut value, &drops));
+    assert_eq!(drops.originals.get(), 0);
+    assert_eq!(drops.reborrows.get(), 0);
+
+    #[cfg(not(maybe_borrow_polonius))]
+    assert!(!ptr.original);
+    drop(ptr);
+    #[cfg(not(maybe_borrow_polonius))]
+    assert_eq!((drops.originals.get(), drops.reborrows.get()), (0, 1));
+    assert_eq!(value, 1);
+}
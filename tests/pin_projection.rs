@@ -0,0 +1,90 @@
+use std::{
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+
+use futures::{executor::block_on, prelude::*};
+
+use pin_project_lite::pin_project;
+
+use maybe_borrow::prelude::*;
+
+pin_project!(
+    #[project = ChunksProj]
+    struct Chunks<T, S> {
+        buffer: Vec<T>,
+        size: usize,
+        done: bool,
+        #[pin]
+        stream: S,
+    }
+);
+
+maybe_borrow::impl_reborrow! {
+    impl<T, S> Reborrow for ChunksProj<'_, T, S> { buffer, size, done, stream }
+}
+
+impl<T, S: Stream<Item = T>> Chunks<T, S> {
+    fn new(stream: S, size: usize) -> Self {
+        Self {
+            buffer: Vec::with_capacity(size),
+            size,
+            done: false,
+            stream,
+        }
+    }
+
+    /// Returns the next full chunk, or the remaining partial chunk once the stream is exhausted.
+    fn poll_next_chunk(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Option<&mut [T]>> {
+        let mut this = self.project();
+
+        if *this.done {
+            this.buffer.clear();
+            return Poll::Ready(None);
+        }
+
+        if this.buffer.len() == *this.size {
+            this.buffer.clear();
+        }
+
+        loop {
+            maybe_borrow!(for<'x> |this| -> Poll<Option<&'x mut [T]>> {
+                let item = ready!(this.stream.as_mut().poll_next(cx));
+
+                let Some(item) = item else {
+                    *this.done = true;
+                    let buffer = this.buffer;
+                    return_borrowed!(Poll::Ready(Some(&mut buffer[..]).filter(|b| !b.is_empty())));
+                };
+
+                this.buffer.push(item);
+
+                if this.buffer.len() == *this.size {
+                    let buffer = this.buffer;
+                    return_borrowed!(Poll::Ready(Some(&mut buffer[..])));
+                }
+            });
+
+            // The projection is still fully usable after a non-returning iteration.
+            debug_assert!(this.buffer.len() < *this.size);
+        }
+    }
+}
+
+#[test]
+fn test_chunks() {
+    block_on(async {
+        let mut chunks = pin!(Chunks::new(stream::iter([1, 2, 3, 4, 5, 6, 7]), 3));
+
+        let items = futures::stream::poll_fn(move |cx| {
+            chunks
+                .as_mut()
+                .poll_next_chunk(cx)
+                .map(|x| x.map(|x| x.to_vec()))
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(items, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    });
+}
@@ -0,0 +1,181 @@
+use std::{
+    num::ParseIntError,
+    pin::{pin, Pin},
+    task::{Context, Poll},
+};
+
+use futures::{prelude::*, task::noop_waker_ref};
+
+use maybe_borrow::prelude::*;
+
+#[derive(Debug, PartialEq)]
+struct Error(String);
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Self(err.to_string())
+    }
+}
+
+/// Parses items from `stream` until one is larger than the last item in `max`, returning a
+/// reference to the updated maximum.
+fn poll_next_max<'a, S: Stream<Item = Result<i32, ParseIntError>> + ?Sized>(
+    mut stream: Pin<&mut S>,
+    mut max: &'a mut Vec<i32>,
+    cx: &mut Context,
+) -> Poll<Option<Result<&'a mut i32, Error>>> {
+    loop {
+        maybe_borrow!(for<'x> |max| -> Poll<Option<Result<&'x mut i32, Error>>> {
+            let item = try_ready!(stream.as_mut().poll_next(cx));
+            if max.last().is_none_or(|max| item > *max) {
+                max.push(item);
+                return_ready!(Some(Ok(max.last_mut().unwrap())));
+            }
+        });
+    }
+}
+
+#[test]
+fn try_ready() {
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut max = vec![];
+
+    let mut stream = pin!(stream::iter(["1", "3", "2", "x"]).map(str::parse::<i32>));
+    assert!(matches!(
+        poll_next_max(stream.as_mut(), &mut max, cx),
+        Poll::Ready(Some(Ok(&mut 1)))
+    ));
+    assert!(matches!(
+        poll_next_max(stream.as_mut(), &mut max, cx),
+        Poll::Ready(Some(Ok(&mut 3)))
+    ));
+    assert!(matches!(
+        poll_next_max(stream.as_mut(), &mut max, cx),
+        Poll::Ready(Some(Err(Error(_))))
+    ));
+    assert!(matches!(
+        poll_next_max(stream.as_mut(), &mut max, cx),
+        Poll::Ready(None)
+    ));
+    assert_eq!(max, [1, 3]);
+
+    let mut stream = pin!(stream::pending());
+    assert!(poll_next_max(stream.as_mut(), &mut max, cx).is_pending());
+}
+
+#[test]
+fn ready_some() {
+    /// Returns the first item from `stream` that's present in `slots`.
+    fn poll_find<'a>(
+        mut stream: Pin<&mut dyn Stream<Item = usize>>,
+        mut slots: &'a mut [i32],
+        cx: &mut Context,
+    ) -> Poll<Option<&'a mut i32>> {
+        loop {
+            maybe_borrow!(for<'x> |slots| -> Poll<Option<&'x mut i32>> {
+                let index = ready_some!(stream.as_mut().poll_next(cx));
+                if let Some(slot) = slots.get_mut(index) {
+                    return_ready!(Some(slot));
+                }
+            });
+        }
+    }
+
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut slots = [1, 2];
+
+    let mut stream = pin!(stream::iter([5, 1, 7]));
+    let Poll::Ready(Some(slot)) = poll_find(stream.as_mut(), &mut slots, cx) else {
+        panic!("expected slot");
+    };
+    *slot += 10;
+    assert!(matches!(
+        poll_find(stream.as_mut(), &mut slots, cx),
+        Poll::Ready(None)
+    ));
+    assert_eq!(slots, [1, 12]);
+
+    let mut stream = pin!(stream::pending());
+    assert!(poll_find(stream.as_mut(), &mut slots, cx).is_pending());
+}
+
+#[test]
+fn in_match_arms() {
+    fn poll_first_even<'a>(
+        mut stream: Pin<&mut dyn Stream<Item = i32>>,
+        mut values: &'a mut Vec<i32>,
+        cx: &mut Context,
+    ) -> Poll<Option<&'a mut i32>> {
+        loop {
+            maybe_borrow_match!(for<'x> |values| -> Poll<Option<&'x mut i32>> {
+                match ready_some!(stream.as_mut().poll_next(cx)) {
+                    x if x % 2 == 0 => borrow {
+                        values.push(x);
+                        Poll::Ready(values.last_mut())
+                    }
+                    _ => continue,
+                }
+            });
+        }
+    }
+
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut values = vec![];
+    let mut stream = pin!(stream::iter([1, 2, 3]));
+
+    assert!(matches!(
+        poll_first_even(stream.as_mut(), &mut values, cx),
+        Poll::Ready(Some(&mut 2))
+    ));
+    assert!(matches!(
+        poll_first_even(stream.as_mut(), &mut values, cx),
+        Poll::Ready(None)
+    ));
+}
+
+#[test]
+fn in_try_maybe_borrow() {
+    /// Returns the slot at the next index from `stream`, or an error if it's out of bounds.
+    fn poll_slot<'a>(
+        mut stream: Pin<&mut dyn Stream<Item = Result<usize, Error>>>,
+        mut slots: &'a mut [i32],
+        cx: &mut Context,
+    ) -> Poll<Option<Result<&'a mut i32, Error>>> {
+        try_maybe_borrow!(
+            for<'x> |slots| -> Poll<Option<Result<&'x mut i32, Error>>> {
+                let index = try_ready!(stream.as_mut().poll_next(cx));
+                if let Some(slot) = slots.get_mut(index) {
+                    return_ready!(Some(Ok(slot)));
+                }
+                Err(Error(format!("{index} is out of bounds")))?;
+            }
+        );
+
+        unreachable!()
+    }
+
+    let cx = &mut Context::from_waker(noop_waker_ref());
+    let mut slots = [1, 2];
+    let mut stream = pin!(stream::iter([Ok(1), Err(Error("x".into())), Ok(2)]));
+
+    let Poll::Ready(Some(Ok(slot))) = poll_slot(stream.as_mut(), &mut slots, cx) else {
+        panic!("expected slot");
+    };
+    *slot += 10;
+    assert!(matches!(
+        poll_slot(stream.as_mut(), &mut slots, cx),
+        Poll::Ready(Some(Err(Error(msg)))) if msg == "x"
+    ));
+    assert!(matches!(
+        poll_slot(stream.as_mut(), &mut slots, cx),
+        Poll::Ready(Some(Err(Error(msg)))) if msg.contains("out of bounds")
+    ));
+    assert!(matches!(
+        poll_slot(stream.as_mut(), &mut slots, cx),
+        Poll::Ready(None)
+    ));
+    assert_eq!(slots, [1, 12]);
+
+    let mut stream = pin!(stream::pending());
+    assert!(poll_slot(stream.as_mut(), &mut slots, cx).is_pending());
+}
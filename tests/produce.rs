@@ -0,0 +1,189 @@
+use std::{collections::HashMap, task::Poll};
+
+use maybe_borrow::prelude::*;
+
+/// Returns the first value over `min`, or pushes `min` if there isn't one.
+fn first_over(mut values: &mut Vec<i32>, min: i32) -> &mut i32 {
+    maybe_borrow!(for<'x> |values| -> &'x mut i32 {
+        if let Some(i) = values.iter().position(|value| *value > min) {
+            produce!(i);
+        }
+    } produce |i| &mut values[i]);
+
+    values.push(min);
+    values.last_mut().unwrap()
+}
+
+#[test]
+fn produce_with_value() {
+    let mut values = vec![1, 5];
+    *first_over(&mut values, 2) += 1;
+    assert_eq!(values, [1, 6]);
+    *first_over(&mut values, 10) += 1;
+    assert_eq!(values, [1, 6, 11]);
+}
+
+#[test]
+fn produce_without_value() {
+    fn get_or_insert<'a>(mut map: &'a mut HashMap<String, i32>, key: &str) -> &'a mut i32 {
+        maybe_borrow!(for<'x> |map| -> &'x mut i32 {
+            if map.contains_key(key) {
+                produce!();
+            }
+        } produce map.get_mut(key).unwrap());
+
+        map.entry(key.into()).or_insert(0)
+    }
+
+    let mut map = HashMap::new();
+    *get_or_insert(&mut map, "a") += 1;
+    *get_or_insert(&mut map, "a") += 1;
+    assert_eq!(map["a"], 2);
+}
+
+#[test]
+fn continue_value() {
+    fn count_or_first(mut values: &mut Vec<i32>) -> Result<&mut i32, usize> {
+        let len = maybe_borrow!(for<'x> |values| -> Result<&'x mut i32, usize> {
+            if values.len() == 1 {
+                produce!();
+            }
+            values.len()
+        } produce Ok(&mut values[0]));
+
+        values.clear();
+        Err(len)
+    }
+
+    let mut values = vec![1, 2];
+    assert_eq!(count_or_first(&mut values), Err(2));
+    assert!(values.is_empty());
+    assert_eq!(count_or_first(&mut vec![3]), Ok(&mut 3));
+}
+
+#[test]
+fn non_borrowing_return() {
+    fn ready_first(mut values: &mut Vec<Poll<i32>>) -> Poll<Option<&mut i32>> {
+        maybe_borrow!(for<'x> |values| -> Poll<Option<&'x mut i32>> {
+            if values.is_empty() {
+                return_ready!(None);
+            }
+            ready!(values[0]);
+            produce!();
+        } produce match &mut values[0] {
+            Poll::Ready(value) => Poll::Ready(Some(value)),
+            Poll::Pending => unreachable!(),
+        })
+    }
+
+    assert_eq!(ready_first(&mut vec![]), Poll::Ready(None));
+    assert_eq!(ready_first(&mut vec![Poll::Pending]), Poll::Pending);
+    assert_eq!(
+        ready_first(&mut vec![Poll::Ready(1)]),
+        Poll::Ready(Some(&mut 1))
+    );
+}
+
+#[test]
+fn multiple_lifetimes() {
+    fn first_positive_pair<'a, 'b>(
+        mut a: &'a mut Vec<i32>,
+        mut b: &'b mut Vec<i32>,
+    ) -> Option<(&'a mut i32, &'b mut i32)> {
+        maybe_borrow!(for<'x, 'y> |a, b| -> Option<(&'x mut i32, &'y mut i32)> {
+            let i = a.iter().position(|value| *value > 0);
+            let j = b.iter().position(|value| *value > 0);
+            if let (Some(i), Some(j)) = (i, j) {
+                produce!((i, j));
+            }
+        } produce |(i, j)| Some((&mut a[i], &mut b[j])));
+
+        a.clear();
+        None
+    }
+
+    let (mut a, mut b) = (vec![0, 1], vec![2]);
+    assert_eq!(first_positive_pair(&mut a, &mut b), Some((&mut 1, &mut 2)));
+    assert_eq!(first_positive_pair(&mut a, &mut vec![0]), None);
+    assert!(a.is_empty());
+}
+
+#[test]
+fn bounded_lifetime() {
+    fn min<'a: 'c, 'b: 'c, 'c>(mut a: &'a mut i32, mut b: &'b mut i32) -> &'c mut i32 {
+        maybe_borrow!(for<'x, 'y, 'z where 'x: 'z, 'y: 'z> |a, b| -> &'z mut i32 {
+            if *a < *b {
+                produce!();
+            }
+        } produce a);
+
+        b
+    }
+
+    let (mut a, mut b) = (1, 2);
+    *min(&mut a, &mut b) += 10;
+    *min(&mut a, &mut b) += 10;
+    assert_eq!((a, b), (11, 12));
+}
+
+#[test]
+fn carried_value() {
+    fn get_or_insert_lowercase(
+        mut map: &mut HashMap<String, usize>,
+        mut key: String,
+    ) -> Result<&mut usize, String> {
+        maybe_borrow!(for<'x> |map, move key| -> Result<&'x mut usize, String> {
+            if key.is_empty() {
+                return_borrowed!(Err(key));
+            }
+            if key.chars().all(char::is_lowercase) {
+                produce!(key);
+            }
+        } produce |key| {
+            let len = key.len();
+            Ok(map.entry(key).or_insert(len))
+        });
+
+        Err(key.to_lowercase())
+    }
+
+    let mut map = HashMap::new();
+    assert_eq!(*get_or_insert_lowercase(&mut map, "abc".into()).unwrap(), 3);
+    assert_eq!(
+        get_or_insert_lowercase(&mut map, "ABC".into()).unwrap_err(),
+        "abc"
+    );
+    assert_eq!(
+        get_or_insert_lowercase(&mut map, "".into()).unwrap_err(),
+        ""
+    );
+}
+
+#[test]
+fn nested() {
+    fn lookup<'a>(mut map: &'a mut HashMap<String, i32>, key: &str) -> Option<&'a mut i32> {
+        try_maybe_borrow!(for<'x> |map| -> Option<&'x mut i32> {
+            maybe_borrow!(for<'x> |map| -> Option<&'x mut i32> {
+                if map.contains_key(key) {
+                    produce!();
+                }
+            } produce map.get_mut(key));
+
+            let default = map.get_mut("default")?;
+            if *default > 0 {
+                return_borrowed!(Some(default));
+            }
+        });
+
+        None
+    }
+
+    let mut map = HashMap::from([("default".to_string(), 1)]);
+    assert_eq!(lookup(&mut map, "a"), Some(&mut 1));
+    map.insert("a".into(), 2);
+    assert_eq!(lookup(&mut map, "a"), Some(&mut 2));
+    map.clear();
+    assert_eq!(lookup(&mut map, "a"), None);
+    map.insert("default".into(), 0);
+    assert_eq!(lookup(&mut map, "a"), None);
+}
@@ -0,0 +1,105 @@
+use std::{cell::Cell, ptr::NonNull};
+
+use maybe_borrow::{prelude::*, RawMut, RawRef};
+
+/// An intrusive list node, linked by raw pointers.
+struct Node {
+    value: i32,
+    next: Option<NonNull<Node>>,
+}
+
+impl Node {
+    fn new(value: i32, next: Option<NonNull<Node>>) -> Self {
+        Self { value, next }
+    }
+}
+
+/// Returns the first value greater than `min`, or appends `min + 1` to the last node otherwise.
+///
+/// # Safety
+/// Every `next` pointer must be valid and not aliased for `'a`, and `spare` must be unlinked.
+unsafe fn first_greater_or_append<'a>(
+    mut node: RawMut<'a, Node>,
+    min: i32,
+    spare: &'a mut Node,
+) -> &'a mut i32 {
+    loop {
+        let advanced = maybe_borrow!(for<'x> |node| -> &'x mut i32 {
+            if node.value > min {
+                return_borrowed!(&mut RawMut::into_mut(node).value);
+            }
+            if let Some(next) = node.next {
+                continue_with!(node, unsafe { RawMut::from_non_null(next) }, true);
+            }
+            false
+        });
+
+        if !advanced {
+            spare.value = min + 1;
+            node.next = Some(NonNull::from(&mut *spare));
+            return &mut spare.value;
+        }
+    }
+}
+
+#[test]
+fn intrusive_list() {
+    let (mut unused, mut spare) = (Node::new(0, None), Node::new(0, None));
+    let mut third = Node::new(3, None);
+    let mut second = Node::new(2, Some(NonNull::from(&mut third)));
+    let mut first = Node::new(1, Some(NonNull::from(&mut second)));
+
+    *unsafe { first_greater_or_append(RawMut::new(&mut first), 1, &mut unused) } += 10;
+    *unsafe { first_greater_or_append(RawMut::new(&mut first), 20, &mut spare) } += 10;
+
+    assert_eq!(unused.value, 0);
+    assert_eq!((first.value, second.value), (1, 12));
+    assert!(third.next.is_some());
+    assert_eq!(spare.value, 31);
+}
+
+/// A handle to a value owned elsewhere, as an FFI wrapper might hold.
+struct Handle<'a> {
+    counter: RawRef<'a, Cell<u32>>,
+    name: RawMut<'a, String>,
+}
+
+maybe_borrow::impl_reborrow! {
+    impl Reborrow for Handle<'_> { counter, name }
+}
+
+/// Returns the name if it's been set, or counts the miss and sets it otherwise.
+fn name_or_set(mut handle: Handle<'_>) -> &str {
+    maybe_borrow!(for<'x> |handle| -> &'x str {
+        if !handle.name.is_empty() {
+            return_borrowed!(RawRef::get(RawMut::into_ref(handle.name)).as_str());
+        }
+    });
+
+    handle.counter.set(handle.counter.get() + 1);
+    handle.name.push_str("unnamed");
+    RawMut::into_mut(handle.name)
+}
+
+#[test]
+fn fields() {
+    let (counter, mut name) = (Cell::new(0), String::new());
+    for _ in 0..2 {
+        let handle = Handle {
+            counter: RawRef::new(&counter),
+            name: RawMut::new(&mut name),
+        };
+        assert_eq!(name_or_set(handle), "unnamed");
+    }
+    assert_eq!(counter.get(), 1);
+}
+
+maybe_borrow::reborrow_conformance_tests!(raw_mut, for<'a> RawMut<'a, i32>: Deref, |check| {
+    let mut value = 1;
+    check(RawMut::new(&mut value));
+    assert_eq!(value, 1);
+});
+
+maybe_borrow::reborrow_conformance_tests!(raw_ref, for<'a> RawRef<'a, str>: Deref, |check| {
+    check(RawRef::new("abc"));
+});
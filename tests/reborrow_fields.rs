@@ -0,0 +1,66 @@
+use std::pin::Pin;
+
+use maybe_borrow::{prelude::*, ReborrowFields};
+
+/// A bundle whose pointers are private and stored in a tuple struct.
+struct Slots<'a, T>(&'a mut Vec<T>, Pin<&'a mut Option<T>>);
+
+impl<'ptr, T> ReborrowFields<'ptr> for Slots<'ptr, T> {
+    type Fields = (&'ptr mut Vec<T>, Pin<&'ptr mut Option<T>>);
+
+    fn into_fields(self) -> Self::Fields {
+        (self.0, self.1)
+    }
+
+    fn from_fields((items, spare): Self::Fields) -> Self {
+        Slots(items, spare)
+    }
+
+    fn reborrow_fields(this: &mut Self) -> (&mut Vec<T>, Pin<&mut Option<T>>) {
+        (&mut *this.0, this.1.as_mut())
+    }
+}
+
+maybe_borrow::impl_reborrow! {
+    impl<T> Reborrow for Slots<'_, T> via ReborrowFields where T: Unpin
+}
+
+impl<'a, T: Unpin> Slots<'a, T> {
+    /// Returns the first item matching `predicate`, storing `default` as the spare otherwise.
+    fn find_or_spare(self, predicate: impl Fn(&T) -> bool, default: T) -> &'a mut T {
+        let mut this = self;
+        maybe_borrow!(for<'x> |this| -> &'x mut T {
+            if let Some(item) = this.0.iter_mut().find(|item| predicate(item)) {
+                return_borrowed!(item);
+            }
+        });
+
+        this.1.get_mut().insert(default)
+    }
+}
+
+#[test]
+fn borrowed_and_restored() {
+    let (mut items, mut spare) = (vec![1, 2, 3], None);
+
+    *Slots(&mut items, Pin::new(&mut spare)).find_or_spare(|x| *x > 1, 0) += 10;
+    assert_eq!(items, [1, 12, 3]);
+
+    *Slots(&mut items, Pin::new(&mut spare)).find_or_spare(|x| *x > 20, 0) += 10;
+    assert_eq!(spare, Some(10));
+}
+
+#[test]
+fn reborrow_keeps_fields() {
+    fn push_twice<T: Copy + Unpin>(mut slots: Slots<T>, value: T) {
+        for _ in 0..2 {
+            let reborrowed: Slots<T> = maybe_borrow::Reborrow::reborrow(&mut slots);
+            reborrowed.0.push(value);
+        }
+        slots.1.set(Some(value));
+    }
+
+    let (mut items, mut spare) = (vec![], None);
+    push_twice(Slots(&mut items, Pin::new(&mut spare)), 1);
+    assert_eq!((items, spare), (vec![1, 1], Some(1)));
+}
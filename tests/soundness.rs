@@ -0,0 +1,339 @@
+//! Exercises the unsafe core behind `maybe_borrow!` for every `Reborrow` impl.
+//!
+//! These tests are intended to be run under Miri with both aliasing models:
+//!
+//! ```plain
+//! cargo +nightly miri test --test soundness
+//! MIRIFLAGS=-Zmiri-tree-borrows cargo +nightly miri test --test soundness
+//! ```
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    pin::{pin, Pin},
+};
+
+use maybe_borrow::prelude::*;
+
+fn shared_first_positive(mut slice: &[i32]) -> Result<&i32, &[i32]> {
+    maybe_borrow!(for<'x> |slice| -> Result<&'x i32, &'x [i32]> {
+        if let Some(x) = slice.iter().find(|x| **x > 0) {
+            return_borrowed!(Ok(x));
+        }
+    });
+
+    Err(slice)
+}
+
+#[test]
+fn shared_ref() {
+    let data = [-1, -2, 3];
+    assert_eq!(shared_first_positive(&data), Ok(&3));
+
+    let data = [-1, -2, -3];
+    let rest = shared_first_positive(&data).unwrap_err();
+    assert_eq!(rest, &data[..]);
+    assert_eq!(rest.as_ptr(), data.as_ptr());
+}
+
+fn mut_first_positive(mut slice: &mut [i32]) -> Result<&mut i32, &mut [i32]> {
+    maybe_borrow!(for<'x> |slice| -> Result<&'x mut i32, &'x mut [i32]> {
+        if let Some(i) = slice.iter().position(|x| *x > 0) {
+            return_borrowed!(Ok(&mut slice[i]));
+        }
+        // Write through the reborrow on the continue path too.
+        slice.iter_mut().for_each(|x| *x -= 1);
+    });
+
+    Err(slice)
+}
+
+#[test]
+fn mut_ref() {
+    let mut data = [-1, -2, 3];
+    *mut_first_positive(&mut data).unwrap() += 10;
+    assert_eq!(data, [-1, -2, 13]);
+
+    let mut data = [-1, -2, -3];
+    let rest = mut_first_positive(&mut data).unwrap_err();
+    rest[0] = 100;
+    assert_eq!(data, [100, -3, -4]);
+}
+
+#[test]
+fn mut_ref_continue_in_loop() {
+    fn last_even(mut v: &mut Vec<i32>) -> &mut i32 {
+        loop {
+            maybe_borrow!(for<'x> |v| -> &'x mut i32 {
+                if let Some(x) = v.last_mut().filter(|x| **x % 2 == 0) {
+                    return_borrowed!(x);
+                }
+            });
+            v.push(v.len() as i32);
+        }
+    }
+
+    let mut v = vec![1, 3];
+    *last_even(&mut v) += 100;
+    assert_eq!(v, [1, 3, 102]);
+}
+
+fn pinned_first_positive(mut slice: Pin<&mut [i32]>) -> Result<&mut i32, Pin<&mut [i32]>> {
+    maybe_borrow!(for<'x> |slice| -> Result<&'x mut i32, Pin<&'x mut [i32]>> {
+        if let Some(i) = slice.iter().position(|x| *x > 0) {
+            // `i32: Unpin`, so this doesn't break any pinning invariant.
+            return_borrowed!(Ok(&mut slice.get_mut()[i]));
+        }
+    });
+
+    Err(slice)
+}
+
+#[test]
+fn pin_mut() {
+    let mut data = pin!([-1, -2, 3]);
+    *pinned_first_positive(data.as_mut()).unwrap() += 10;
+    assert_eq!(*data, [-1, -2, 13]);
+
+    let mut data = pin!([-1, -2, -3]);
+    let mut rest = pinned_first_positive(data.as_mut()).unwrap_err();
+    rest[0] = 100;
+    assert_eq!(*data, [100, -2, -3]);
+}
+
+#[test]
+fn pin_shared() {
+    fn first_positive(mut slice: Pin<&[i32]>) -> Option<&i32> {
+        maybe_borrow!(for<'x> |slice| -> Option<&'x i32> {
+            if let found @ Some(_) = slice.get_ref().iter().find(|x| **x > 0) {
+                return_borrowed!(found);
+            }
+        });
+
+        assert!(!slice.is_empty());
+        None
+    }
+
+    let data = pin!([-1, -2, 3]);
+    assert_eq!(first_positive(data.as_ref()), Some(&3));
+
+    let data = pin!([-1, -2, -3]);
+    assert_eq!(first_positive(data.as_ref()), None);
+}
+
+fn pair_max<'a>(mut a: &'a mut i32, mut b: &'a mut i32) -> &'a mut i32 {
+    maybe_borrow!(for<'x> |a, b| -> &'x mut i32 {
+        if *a >= *b {
+            return_borrowed!(a);
+        }
+        *a += 1;
+    });
+
+    b
+}
+
+#[test]
+fn tuple() {
+    let (mut a, mut b) = (5, 3);
+    *pair_max(&mut a, &mut b) = 0;
+    assert_eq!((a, b), (0, 3));
+
+    let (mut a, mut b) = (3, 5);
+    *pair_max(&mut a, &mut b) = 0;
+    assert_eq!((a, b), (4, 0));
+}
+
+#[test]
+fn tuple_of_three() {
+    fn first_nonzero<'a>(
+        mut a: &'a mut i32,
+        mut b: &'a mut i32,
+        mut c: &'a i32,
+    ) -> Result<&'a mut i32, (&'a mut i32, &'a mut i32, &'a i32)> {
+        maybe_borrow!(
+            for<'x> |a, b, c| -> Result<&'x mut i32, (&'x mut i32, &'x mut i32, &'x i32)> {
+                if *a != 0 {
+                    return_borrowed!(Ok(a));
+                }
+                if *b != 0 {
+                    return_borrowed!(Ok(b));
+                }
+            }
+        );
+
+        Err((a, b, c))
+    }
+
+    let (mut a, mut b, c) = (0, 2, 3);
+    *first_nonzero(&mut a, &mut b, &c).unwrap() += 1;
+    assert_eq!(b, 3);
+
+    let (mut a, mut b, c) = (0, 0, 3);
+    let (a2, b2, c2) = first_nonzero(&mut a, &mut b, &c).unwrap_err();
+    *a2 = 1;
+    *b2 = *c2;
+    assert_eq!((a, b), (1, 3));
+}
+
+#[test]
+fn multiple_lifetimes() {
+    fn pick<'a, 'b>(
+        mut a: &'a mut Vec<i32>,
+        mut b: &'b mut Vec<i32>,
+    ) -> Option<(&'a mut i32, &'b mut i32)> {
+        maybe_borrow!(for<'x, 'y> |a, b| -> Option<(&'x mut i32, &'y mut i32)> {
+            if let (Some(x), Some(y)) = (a.first_mut(), b.first_mut()) {
+                return_borrowed!(Some((x, y)));
+            }
+        });
+
+        a.push(3);
+        b.push(4);
+        None
+    }
+
+    let (mut a, mut b) = (vec![1], vec![2]);
+    let (x, y) = pick(&mut a, &mut b).unwrap();
+    std::mem::swap(x, y);
+    assert_eq!((a, b), (vec![2], vec![1]));
+
+    let (mut a, mut b) = (vec![1], vec![]);
+    assert!(pick(&mut a, &mut b).is_none());
+    assert_eq!((a, b), (vec![1, 3], vec![4]));
+}
+
+#[test]
+fn multiple_lifetimes_mixed_with_pin() {
+    fn pick<'a, 'b>(
+        mut a: Pin<&'a mut i32>,
+        mut b: &'b mut i32,
+    ) -> Option<(&'a mut i32, &'b mut i32)> {
+        maybe_borrow!(for<'x, 'y> |a, b| -> Option<(&'x mut i32, &'y mut i32)> {
+            if *a == *b {
+                return_borrowed!(Some((a.get_mut(), b)));
+            }
+        });
+
+        *a.as_mut() += 1;
+        *b -= 1;
+        None
+    }
+
+    let (mut a, mut b) = (1, 1);
+    let (x, y) = pick(Pin::new(&mut a), &mut b).unwrap();
+    *x = 10;
+    *y = 20;
+    assert_eq!((a, b), (10, 20));
+
+    let (mut a, mut b) = (1, 2);
+    assert!(pick(Pin::new(&mut a), &mut b).is_none());
+    assert_eq!((a, b), (2, 1));
+}
+
+#[test]
+fn try_continue_path() {
+    fn first_some(mut v: &mut [Option<i32>]) -> Option<&mut i32> {
+        try_maybe_borrow!(for<'x> |v| -> Option<&'x mut i32> {
+            let first = v.first_mut()?;
+            if let Some(x) = first {
+                return_borrowed!(Some(x));
+            }
+        });
+
+        v.iter_mut().flatten().next()
+    }
+
+    let mut v = [None, Some(1)];
+    *first_some(&mut v).unwrap() += 1;
+    assert_eq!(v, [None, Some(2)]);
+
+    let mut v = [Some(1), None];
+    *first_some(&mut v).unwrap() += 1;
+    assert_eq!(v, [Some(2), None]);
+
+    assert_eq!(first_some(&mut []), None);
+}
+
+#[test]
+fn replace_pointer() {
+    fn last_mut(mut v: &mut [i32]) -> &mut [i32] {
+        loop {
+            let advanced = maybe_borrow!(for<'x> |v| -> &'x mut [i32] {
+                if let [_, rest @ ..] = v {
+                    if !rest.is_empty() {
+                        continue_with!(v, rest, true);
+                    }
+                }
+                false
+            });
+
+            if !advanced {
+                return v;
+            }
+        }
+    }
+
+    let mut data = [1, 2, 3];
+    last_mut(&mut data)[0] += 10;
+    assert_eq!(data, [1, 2, 13]);
+}
+
+#[test]
+fn replace_pinned_pointer() {
+    fn replace(mut x: Pin<&mut [i32; 2]>) -> Pin<&mut [i32; 2]> {
+        maybe_borrow!(for<'x> |x| -> Pin<&'x mut [i32; 2]> {
+            x.as_mut().get_mut()[0] += 1;
+            if x[0] > 0 {
+                continue_with!(x, x);
+            }
+        });
+        x
+    }
+
+    let mut x = pin!([1, 2]);
+    replace(x.as_mut()).get_mut()[1] += 1;
+    assert_eq!(*x, [2, 3]);
+}
+
+#[test]
+fn panic_inside_block() {
+    fn panicking(mut v: &mut Vec<i32>) -> Option<&mut i32> {
+        maybe_borrow!(for<'x> |v| -> Option<&'x mut i32> {
+            v.push(1);
+            if v.len() > 1 {
+                panic!("expected panic");
+            }
+        });
+
+        v.last_mut()
+    }
+
+    let mut v = vec![];
+    assert_eq!(panicking(&mut v).copied(), Some(1));
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        panicking(&mut v);
+    }));
+    assert!(result.is_err());
+
+    // The target must still be fully usable after unwinding out of the block.
+    v.push(3);
+    assert_eq!(v, [1, 1, 3]);
+}
+
+#[test]
+fn panic_inside_pinned_block() {
+    fn panicking(mut x: Pin<&mut i32>) {
+        maybe_borrow!(for<'x> |x| -> () {
+            *x.as_mut() += 1;
+            if *x > 0 {
+                panic!("expected panic");
+            }
+        });
+    }
+
+    let mut x = pin!(0);
+    let result = catch_unwind(AssertUnwindSafe(|| panicking(x.as_mut())));
+    assert!(result.is_err());
+
+    *x.as_mut() += 1;
+    assert_eq!(*x, 2);
+}
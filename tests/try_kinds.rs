@@ -0,0 +1,165 @@
+use std::{collections::HashMap, num::ParseIntError, ops::ControlFlow, task::Poll};
+
+use maybe_borrow::prelude::*;
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Missing,
+    Parse(ParseIntError),
+}
+
+impl From<ParseIntError> for Error {
+    fn from(e: ParseIntError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+/// Returns the value for `key` if it's at least `min`, where `min` is looked up by name.
+fn at_least<'a>(
+    mut map: &'a mut HashMap<String, i32>,
+    key: &str,
+    min: &str,
+) -> Result<Option<&'a mut i32>, Error> {
+    try_maybe_borrow!(for<'x> |map| -> Result<Option<&'x mut i32>, Error> as Option<_> {
+        let min = *map.get(min)?;
+        let value = map.get_mut(key)?;
+        if *value >= min {
+            return_borrowed!(Ok(Some(value)));
+        }
+    } else Error::Missing);
+
+    map.insert(key.into(), 0);
+    Ok(None)
+}
+
+#[test]
+fn option_in_result() {
+    let mut map = HashMap::from([("a".to_string(), 5), ("min".to_string(), 3)]);
+    *at_least(&mut map, "a", "min").unwrap().unwrap() += 1;
+    assert_eq!(map["a"], 6);
+    assert_eq!(at_least(&mut map, "b", "min"), Err(Error::Missing));
+    assert_eq!(at_least(&mut map, "a", "max"), Err(Error::Missing));
+
+    map.insert("min".into(), 10);
+    assert_eq!(at_least(&mut map, "a", "min"), Ok(None));
+    assert_eq!(map["a"], 0);
+}
+
+/// Returns the element at the index parsed from `index`, or pushes one if it's out of bounds.
+fn parsed_index<'a>(mut values: &'a mut Vec<i32>, index: &str) -> Result<&'a mut i32, Error> {
+    try_maybe_borrow!(for<'x> |values| -> Result<&'x mut i32, Error> as Result<_, ParseIntError> {
+        let index: usize = index.parse()?;
+        if let Some(value) = values.get_mut(index) {
+            return_borrowed!(Ok(value));
+        }
+    });
+
+    values.push(0);
+    Ok(values.last_mut().unwrap())
+}
+
+#[test]
+fn result_with_conversion() {
+    let mut values = vec![1, 2];
+    *parsed_index(&mut values, "1").unwrap() += 10;
+    *parsed_index(&mut values, "5").unwrap() += 20;
+    assert_eq!(values, [1, 12, 20]);
+    assert!(matches!(
+        parsed_index(&mut values, "x"),
+        Err(Error::Parse(_))
+    ));
+}
+
+/// Returns the first value under `min`, or a missing value's index, or the length if neither is
+/// found.
+fn first_under(mut values: &mut [Option<i32>], min: i32) -> Poll<Option<Result<&mut i32, usize>>> {
+    try_maybe_borrow!(for<'x> |values| -> Poll<Option<Result<&'x mut i32, usize>>> as Option<_> {
+        let first = values.first()?.ok_or(0);
+        if first == Err(0) {
+            return_borrowed!(Poll::Pending);
+        }
+        for (i, value) in values.iter_mut().enumerate() {
+            match value {
+                Some(value) if *value < min => return_ready!(Some(Ok(value))),
+                Some(_) => {}
+                None => return_ready!(Some(Err(i))),
+            }
+        }
+    });
+
+    // Distinct from the `None` that `?` converts, so the conversion is what's checked.
+    Poll::Ready(Some(Err(values.len())))
+}
+
+#[test]
+fn poll() {
+    assert_eq!(first_under(&mut [], 1), Poll::Ready(None));
+    assert_eq!(first_under(&mut [None], 1), Poll::Pending);
+    assert_eq!(
+        first_under(&mut [Some(2), None], 1),
+        Poll::Ready(Some(Err(1)))
+    );
+    assert_eq!(
+        first_under(&mut [Some(2), Some(0)], 1),
+        Poll::Ready(Some(Ok(&mut 0)))
+    );
+    assert_eq!(
+        first_under(&mut [Some(2), Some(3)], 1),
+        Poll::Ready(Some(Err(2)))
+    );
+}
+
+/// Breaks with the index of the first negative value, or returns the first value over `max`.
+fn control_flow(mut values: &mut [i32], max: i32) -> ControlFlow<usize, Option<&mut i32>> {
+    try_maybe_borrow!(for<'x> |values| -> ControlFlow<usize, Option<&'x mut i32>> as ControlFlow<usize, _> {
+        if let Some(i) = values.iter().position(|v| *v < 0) {
+            ControlFlow::Break(i)?;
+        }
+        if let Some(value) = values.iter_mut().find(|v| **v > max) {
+            return_borrowed!(ControlFlow::Continue(Some(value)));
+        }
+    });
+
+    ControlFlow::Continue(values.first_mut())
+}
+
+#[test]
+fn control_flow_residual() {
+    assert_eq!(control_flow(&mut [1, -1], 5), ControlFlow::Break(1));
+    assert_eq!(
+        control_flow(&mut [1, 7], 5),
+        ControlFlow::Continue(Some(&mut 7))
+    );
+    assert_eq!(
+        control_flow(&mut [1, 2], 5),
+        ControlFlow::Continue(Some(&mut 1))
+    );
+}
+
+/// Returns the first negative value or the value at the index parsed from `index`, pushing one if
+/// it's out of bounds.
+fn nested<'a>(mut values: &'a mut Vec<i32>, index: &str) -> Result<&'a mut i32, Error> {
+    try_maybe_borrow!(for<'x> |values| -> Result<&'x mut i32, Error> as Option<_> {
+        maybe_borrow!(for<'x> |values| -> Result<&'x mut i32, Error> {
+            if let Some(value) = values.iter_mut().find(|v| **v < 0) {
+                return_borrowed!(Ok(value));
+            }
+        });
+
+        let index = index.parse::<usize>().ok()?;
+        if let Some(value) = values.get_mut(index) {
+            return_borrowed!(Ok(value));
+        }
+    } else Error::Missing);
+
+    values.push(0);
+    Ok(values.last_mut().unwrap())
+}
+
+#[test]
+fn nested_return() {
+    assert_eq!(nested(&mut vec![1, -2], "0"), Ok(&mut -2));
+    assert_eq!(nested(&mut vec![1, 2], "1"), Ok(&mut 2));
+    assert_eq!(nested(&mut vec![1, 2], "2"), Ok(&mut 0));
+    assert_eq!(nested(&mut vec![1, 2], "x"), Err(Error::Missing));
+}
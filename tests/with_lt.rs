@@ -0,0 +1,88 @@
+use maybe_borrow::{prelude::*, Apply, BorrowedAs, Reborrow, WithLt, WithLt2};
+
+/// A pointer to two buffers, implementing `Reborrow` by hand.
+struct Buffers<'a, T> {
+    front: &'a mut Vec<T>,
+    back: &'a mut Vec<T>,
+}
+
+unsafe impl<'ptr, T> Reborrow<'ptr> for Buffers<'ptr, T> {
+    type BorrowWithLifetime = WithLt!['b -> Buffers<'b, T>];
+
+    fn reborrow<'b>(this: &'b mut Self) -> BorrowedAs<'b, Self::BorrowWithLifetime> {
+        Buffers {
+            front: this.front,
+            back: this.back,
+        }
+    }
+
+    unsafe fn extend<'b>(this: Self) -> BorrowedAs<'b, Self::BorrowWithLifetime> {
+        unsafe {
+            Buffers {
+                front: &mut *(this.front as *mut Vec<T>),
+                back: &mut *(this.back as *mut Vec<T>),
+            }
+        }
+    }
+}
+
+#[test]
+fn hand_written_reborrow() {
+    fn first<T>(mut buffers: Buffers<'_, T>) -> &mut T {
+        maybe_borrow!(for<'x> |buffers| -> &'x mut T {
+            if let Some(first) = buffers.front.first_mut() {
+                return_borrowed!(first);
+            }
+        });
+
+        buffers.back.first_mut().unwrap()
+    }
+
+    let (mut front, mut back) = (vec![], vec![1]);
+    *first(Buffers {
+        front: &mut front,
+        back: &mut back,
+    }) += 1;
+    assert_eq!(back, [2]);
+}
+
+/// Splits values into a part borrowing for `'a` and a part borrowing for `'b`.
+trait Split<W: WithLt2> {
+    fn split<'a, 'b>(&'a mut self, other: &'b mut Self) -> W::Actual<'a, 'b>;
+}
+
+impl Split<WithLt!['a, 'b -> (&'a mut i32, &'b i32)]> for i32 {
+    fn split<'a, 'b>(&'a mut self, other: &'b mut Self) -> (&'a mut i32, &'b i32) {
+        (self, other)
+    }
+}
+
+fn split_both<'a, 'b, W, S>(a: &'a mut S, b: &'b mut S) -> Apply<'a, 'b, W>
+where
+    W: WithLt2,
+    S: Split<W>,
+{
+    a.split(b)
+}
+
+#[test]
+fn two_lifetimes() {
+    let (mut a, mut b) = (1, 2);
+    let (x, y) = split_both(&mut a, &mut b);
+    *x += *y;
+    assert_eq!(a, 3);
+}
+
+#[test]
+fn actual_of_single_lifetime() {
+    fn longest<'a, W: WithLt>(values: Vec<W::Actual<'a>>) -> Option<W::Actual<'a>>
+    where
+        W::Actual<'a>: AsRef<str>,
+    {
+        values.into_iter().max_by_key(|value| value.as_ref().len())
+    }
+
+    let values = ["a".to_string(), "abc".to_string()];
+    let refs = values.iter().map(String::as_str).collect();
+    assert_eq!(longest::<WithLt!['x -> &'x str]>(refs), Some("abc"));
+}